@@ -1,27 +1,33 @@
 use darling::{ast::NestedMeta, FromMeta};
 use heck::{
     ToKebabCase, ToLowerCamelCase, ToPascalCase, ToShoutyKebabCase, ToShoutySnakeCase, ToSnakeCase,
+    ToTitleCase,
 };
 use indexmap::IndexMap;
 use lazy_static::lazy_static;
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::{quote, ToTokens};
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 use std::fs;
-use std::path::Path as StdPath;
+use std::path::{Path as StdPath, PathBuf};
 use std::sync::RwLock;
 use syn::{
-    parse::Parse, parse::ParseStream, parse_macro_input,
+    parse::Parse, punctuated::Punctuated, parse::ParseStream, parse_macro_input,
     Attribute, Data, DataStruct, DeriveInput, Expr, ExprLit, Field, Fields, Lit, Meta,
     MetaList, MetaNameValue, Path, Type, TypePath,
 };
-use tabled::{Table, Tabled};
+use tabled::builder::Builder;
+use tabled::settings::{object::Columns, Modify, Width};
 
-// Global registry for struct definitions and file-specific pending generations
+// Global registry for struct definitions, file-specific pending generations and written files
 lazy_static! {
     static ref STRUCT_REGISTRY: RwLock<HashMap<String, StructInfo>> = RwLock::new(HashMap::new());
     static ref FILE_PENDING_GENERATIONS: RwLock<HashMap<String, Vec<PendingGeneration>>> = RwLock::new(HashMap::new());
+    static ref WRITTEN_PATHS: RwLock<HashMap<String, Vec<String>>> = RwLock::new(HashMap::new());
+    static ref ENUM_REGISTRY: RwLock<HashMap<String, Vec<PossibleValue>>> = RwLock::new(HashMap::new());
+    static ref CONTENT_REGISTRY: RwLock<HashMap<String, String>> = RwLock::new(HashMap::new());
+    static ref DIAGNOSTICS: RwLock<HashMap<String, IndexMap<String, String>>> = RwLock::new(HashMap::new());
 }
 
 /// Information about a pending documentation generation
@@ -83,7 +89,10 @@ impl std::ops::Deref for AttributeArgs {
     }
 }
 
-/// Registration macro for nested structs
+/// Registration macro for nested structs and `ValueEnum` enums
+///
+/// Registered enums list their possible values in the Details of fields using them.
+/// String constants and statics can be registered to be referenced by `render_with`.
 ///
 /// Usage:
 /// ```rust
@@ -96,661 +105,4540 @@ impl std::ops::Deref for AttributeArgs {
 /// ```
 #[proc_macro_attribute]
 pub fn register(_args: TokenStream, input: TokenStream) -> TokenStream {
+    if let Ok(item) = syn::parse::<syn::ItemConst>(input.clone()) {
+        return register_content_definition(&item.ident, &item.expr, item.to_token_stream());
+    }
+    if let Ok(item) = syn::parse::<syn::ItemStatic>(input.clone()) {
+        return register_content_definition(&item.ident, &item.expr, item.to_token_stream());
+    }
+
     let input = parse_macro_input!(input as DeriveInput);
 
-    match register_struct_definition(&input) {
+    let result = match &input.data {
+        Data::Enum(_) => register_enum_definition(&input),
+        _ => register_struct_definition(&input),
+    };
+
+    match result {
         Ok(result) => result,
         Err(err) => err.to_compile_error().into(),
     }
 }
 
+/// Paths written by a struct's `generate` attribute
+///
+/// Expands to a `&[&str]` of the files written so far for the given struct, so tests can
+/// read the generated documentation back without hardcoding its location.
+///
+/// Usage:
+/// ```rust,ignore
+/// let paths: &[&str] = clap_autodoc::written_paths!(Config);
+/// ```
+#[proc_macro]
+pub fn written_paths(input: TokenStream) -> TokenStream {
+    let struct_name = parse_macro_input!(input as syn::Ident);
+
+    let written_paths = WRITTEN_PATHS.read().unwrap();
+    match written_paths.get(&struct_name.to_string()) {
+        Some(paths) => quote! { &[#(#paths),*] }.into(),
+        None => syn::Error::new_spanned(
+            &struct_name,
+            format!("no documentation has been generated for {struct_name}"),
+        )
+        .to_compile_error()
+        .into(),
+    }
+}
+
+/// Render the documentation of a registered struct without writing any file
+///
+/// Expands to a `&str` of the content `generate` would put between the markers, so tests
+/// and build scripts can assert on it directly. Takes the same options as `generate`,
+/// apart from the file related ones.
+///
+/// Usage:
+/// ```rust,ignore
+/// let docs: &str = clap_autodoc::render!(Config, format = "grouped");
+/// ```
+#[proc_macro]
+pub fn render(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as RenderInput);
+
+    let args = match ConfigDocsArgs::from_list(&input.args) {
+        Ok(args) => args,
+        Err(err) => return err.write_errors().into(),
+    };
+
+    match render_docs(&input.struct_name, &args) {
+        Ok(docs) => quote! { #docs }.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Input of the `render!` macro: a struct name optionally followed by `generate` options
+struct RenderInput {
+    struct_name: syn::Ident,
+    args: AttributeArgs,
+}
+
+impl Parse for RenderInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let struct_name = input.parse()?;
+        if !input.is_empty() {
+            input.parse::<syn::Token![,]>()?;
+        }
+        let args = input.parse()?;
+
+        Ok(RenderInput { struct_name, args })
+    }
+}
+
 /// Arguments for the generate attribute
 #[derive(Debug, Clone, FromMeta)]
 struct ConfigDocsArgs {
+    /// Required unless the output is split by group
+    #[darling(default)]
     target: String,
     #[darling(default = "OutputFormat::default")]
     format: OutputFormat,
+    #[darling(default = "TypeStyle::default")]
+    type_style: TypeStyle,
+    #[darling(default)]
+    details_max: Option<usize>,
+    #[darling(default)]
+    field_anchors: bool,
+    #[darling(default)]
+    heading_offset: usize,
+    #[darling(default = "GroupCase::default")]
+    group_case: GroupCase,
+    #[darling(default)]
+    strict: bool,
+    /// Fail unless every field can be set through an environment variable
+    #[darling(default)]
+    require_env: bool,
+    /// Print the issues found by diagnostics as one warning per struct
+    #[darling(default)]
+    warnings: bool,
+    #[darling(default)]
+    details_show_source: bool,
+    #[darling(default)]
+    collapsible: bool,
+    #[darling(default)]
+    title: Option<String>,
+    #[darling(default)]
+    field_filter: Option<String>,
+    #[darling(default)]
+    legend: bool,
+    #[darling(default)]
+    write_if_absent_only: bool,
+    #[darling(default)]
+    split_by_group: bool,
+    #[darling(default)]
+    split_target_template: Option<String>,
+    #[darling(default)]
+    isolate_section: bool,
+    #[darling(default)]
+    crate_name: Option<String>,
+    #[darling(default)]
+    infer_defaults: bool,
+    #[darling(default)]
+    fail_on_shrink: bool,
+    #[darling(default)]
+    diagnostics: Option<String>,
+    #[darling(default)]
+    check: bool,
+    /// In check mode, print a unified diff of stale documentation to stderr
+    #[darling(default)]
+    check_diff: bool,
+    #[darling(default = "PathBase::default")]
+    path_base: PathBase,
+    #[darling(default)]
+    merge_duplicate_groups: bool,
+    #[darling(default)]
+    required_label: Option<String>,
+    #[darling(default)]
+    optional_label: Option<String>,
+    #[darling(default)]
+    empty_placeholder: Option<String>,
+    #[darling(default)]
+    defaults_target: Option<String>,
+    #[darling(default)]
+    marker: Option<String>,
+    #[darling(default)]
+    min_col_width: Option<String>,
+    /// Columns to render, in order, e.g. "name,type,default,details"
+    #[darling(default)]
+    columns: Option<String>,
+    #[darling(default)]
+    number_rows: RowNumbers,
+    #[darling(default)]
+    sort: SortOrder,
+    /// Sort the variables of the `env` format by name and drop repeated ones
+    #[darling(default)]
+    env_sort: bool,
+    /// Add `data-struct` and `data-field` attributes naming each row's source to HTML rows
+    #[darling(default)]
+    emit_source: bool,
+    /// File receiving a hash of the generated region, for CI checks without regenerating
+    #[darling(default)]
+    emit_checksum: Option<String>,
+    /// Order groups by name instead of by first appearance
+    #[darling(default)]
+    sort_groups: bool,
+    /// Other registered structs whose fields are added to the table, `merge = [A, B]`
+    #[darling(default)]
+    merge: Option<StructList>,
+}
+
+impl ConfigDocsArgs {
+    /// Required cell of fields that have to be provided, `Yes` by default
+    fn required_label(&self) -> &str {
+        self.required_label.as_deref().unwrap_or("Yes")
+    }
+
+    /// Required cell of optional fields, `No` by default
+    fn optional_label(&self) -> &str {
+        self.optional_label.as_deref().unwrap_or("No")
+    }
+
+    /// Cell content for a missing env variable or default, `-` by default
+    fn empty_placeholder(&self) -> &str {
+        self.empty_placeholder.as_deref().unwrap_or("-")
+    }
 }
 
 /// Main function to generate configuration documentation with smart dependency resolution
 fn generate_config_docs(input: &DeriveInput, args: &ConfigDocsArgs) -> syn::Result<TokenStream> {
-    let struct_info = parse_struct_info(input)?;
+    validate_args(args)?;
 
-    if can_generate_immediately(&struct_info)? {
-        let expanded_struct_info = expand_nested_structs(struct_info)?;
-
-        let markdown_table = generate_markdown_table(&expanded_struct_info, args)?;
+    let struct_info = merge_structs(parse_struct_info(input)?, args);
 
-        update_target_file(&args.target, &markdown_table)?;
+    if can_generate_immediately(&struct_info)? {
+        write_docs(struct_info, args)?;
     } else {
+        // Report unresolved dependencies now, replaced once they are registered
+        if let Some(path) = diagnostics_path(args) {
+            write_diagnostics(&expand_nested_structs(struct_info.clone(), args)?, &path, args)?;
+        }
+
         let mut file_pending = FILE_PENDING_GENERATIONS.write().unwrap();
         file_pending
             .entry(args.target.clone())
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(PendingGeneration {
                 struct_info,
                 args: args.clone(),
             });
     }
 
-    Ok(quote! { #input }.into())
+    let output = strip_autodoc_attrs(input);
+    Ok(quote! { #output }.into())
 }
 
-/// Register a struct definition in the global registry
-fn register_struct_definition(input: &DeriveInput) -> syn::Result<TokenStream> {
-    let struct_info = parse_struct_info(input)?;
-
-    {
-        let mut registry = STRUCT_REGISTRY.write().unwrap();
-        let struct_name = struct_info.name.clone();
-        registry.insert(struct_name, struct_info);
+/// Add the fields of the structs listed in `merge` as if they were flattened
+///
+/// Leaning on flattening resolves them through the registry, waiting until they are
+/// registered, and groups their fields by source struct.
+fn merge_structs(mut struct_info: StructInfo, args: &ConfigDocsArgs) -> StructInfo {
+    let merged = args.merge.iter().flat_map(|list| list.0.iter().cloned());
+    for name in merged {
+        struct_info.fields.push(FieldInfo {
+            ident: name.to_snake_case(),
+            name: name.to_snake_case(),
+            source_struct: struct_info.name.clone(),
+            in_required_group: false,
+            field_type: name.clone(),
+            value_type: Some(name.clone()),
+            doc_comment: None,
+            clap_attrs: ClapAttrs {
+                flatten: true,
+                ..Default::default()
+            },
+            group: name,
+            is_optional: false,
+            is_multiple: false,
+            is_flag: false,
+            rename_all: struct_info.clap_rename_all,
+            rename_all_env: struct_info.clap_rename_all_env,
+            doc_attrs: FieldDocAttrs::default(),
+        });
     }
 
-    try_process_pending_generations()?;
-
-    Ok(quote! { #input }.into())
+    struct_info
 }
 
-/// Check if a struct can be generated immediately (all dependencies are available)
-fn can_generate_immediately(struct_info: &StructInfo) -> syn::Result<bool> {
-    let registry = STRUCT_REGISTRY.read().unwrap();
-
-    for field in &struct_info.fields {
-        if field.clap_attrs.flatten && !registry.contains_key(&field.field_type) {
-            return Ok(false); // Missing dependency
+/// Validate generate arguments up front, so mistakes are reported even for pending structs
+fn validate_args(args: &ConfigDocsArgs) -> syn::Result<()> {
+    if args.split_by_group {
+        let has_group = args
+            .split_target_template
+            .as_deref()
+            .is_some_and(|template| template.contains("{group}"));
+        if !has_group {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                "split_by_group requires a split_target_template containing `{group}`, \
+                 e.g. \"docs/{group}.md\"",
+            ));
+        }
+        // Each split file gets its own checksum, which would overwrite each other otherwise
+        if let Some(checksum_target) = args
+            .emit_checksum
+            .as_deref()
+            .filter(|target| !target.contains("{group}"))
+        {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                format!(
+                    "emit_checksum `{checksum_target}` requires `{{group}}` with split_by_group, \
+                     e.g. \"docs/{{group}}.md.fnv\""
+                ),
+            ));
         }
+    } else if args.target.is_empty() {
+        return Err(syn::Error::new(Span::call_site(), "Missing field `target`"));
     }
 
-    Ok(true)
+    validate_render_args(args)
 }
 
-/// Try to process any pending generations that now have all dependencies available
-fn try_process_pending_generations() -> syn::Result<()> {
-    let mut file_pending = FILE_PENDING_GENERATIONS.write().unwrap();
-    
-    // Process each target file independently
-    for (_target_file, pending_list) in file_pending.iter_mut() {
-        let mut remaining_pending = Vec::new();
-        
-        for pending_gen in pending_list.drain(..) {
-            if can_generate_immediately(&pending_gen.struct_info)? {
-                let expanded_struct_info = expand_nested_structs(pending_gen.struct_info)?;
-                let markdown_table =
-                    generate_markdown_table(&expanded_struct_info, &pending_gen.args)?;
-                update_target_file(&pending_gen.args.target, &markdown_table)?;
-            } else {
-                remaining_pending.push(pending_gen);
-            }
+/// Validate the arguments that shape the rendered content, shared with `render!`
+fn validate_render_args(args: &ConfigDocsArgs) -> syn::Result<()> {
+    let renders_html = matches!(
+        args.format,
+        OutputFormat::Flat
+            | OutputFormat::Grouped
+            | OutputFormat::Summary
+            | OutputFormat::Sections
+            | OutputFormat::Html
+    );
+    if args.field_anchors && !renders_html {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "field_anchors requires a markdown or html format; json, yaml, toml, env, dotenv \
+             and roff output cannot hold HTML anchors",
+        ));
+    }
+
+    if let Some(spec) = &args.min_col_width {
+        parse_min_col_widths(spec)?;
+    }
+
+    if let Some(spec) = &args.columns {
+        parse_columns(spec)?;
+    }
+
+    if let Some(filter) = &args.field_filter {
+        if filter.is_empty() || filter.contains(['^', '$', '(', ')', '[', ']', '|', '+', '\\']) {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                format!(
+                    "invalid field_filter `{filter}`: expected a glob pattern using `*` and `?`, \
+                     e.g. \"postgres_*\""
+                ),
+            ));
         }
-        
-        *pending_list = remaining_pending;
     }
-    
-    // Remove empty entries to keep the map clean
-    file_pending.retain(|_, pending_list| !pending_list.is_empty());
 
     Ok(())
 }
 
-/// Output format for the markdown table
-#[derive(Debug, Clone, FromMeta, Default)]
-enum OutputFormat {
-    #[darling(rename = "flat")]
-    #[default]
-    Flat,
-    #[darling(rename = "grouped")]
-    Grouped,
-}
+/// Expand a struct whose dependencies are all registered and write its documentation
+fn write_docs(struct_info: StructInfo, args: &ConfigDocsArgs) -> syn::Result<()> {
+    let expanded_struct_info = prepare_fields(struct_info, args)?;
 
-/// Information about a struct field
-#[derive(Debug, Clone)]
-struct FieldInfo {
-    name: String,
-    field_type: String,
-    doc_comment: Option<String>,
-    clap_attrs: ClapAttrs,
-    group: String,
-}
+    if args.warnings {
+        warn_issues(&expanded_struct_info);
+    }
 
-/// Clap attributes for a field 
-#[derive(Debug, Clone, Default)]
-struct ClapAttrs {
-    // Value attributes
-    default_value: Option<String>,
-    default_value_t: Option<String>,
-    
-    // Naming attributes
-    rename: Option<String>,
-    long: Option<String>,
-    short: Option<char>,
-    
-    // Behavioral flags
-    flatten: bool,
-    required: bool,
-    skip: bool,
-    
-    // Documentation attributes
-    help: Option<String>,
-    about: Option<String>,
-    
-    // Environment binding
-    env: Option<String>,
-}
+    if let Some(path) = diagnostics_path(args) {
+        return write_diagnostics(&expanded_struct_info, &path, args);
+    }
 
-/// Information about the entire struct
-#[derive(Debug, Clone)]
-struct StructInfo {
-    name: String,
-    fields: Vec<FieldInfo>,
-    clap_rename_all: Option<CaseStyle>,
-}
+    if let Some(defaults_target) = &args.defaults_target {
+        write_defaults_file(&expanded_struct_info, args, defaults_target)?;
+    }
 
-#[derive(Debug, Clone, Copy)]
-enum CaseStyle {
-    Snake,
-    Camel,
-    Pascal,
-    Kebab,
-    ScreamingSnake,
-    ScreamingKebab,
-}
+    if let (true, Some(template)) = (args.split_by_group, &args.split_target_template) {
+        return write_split_docs(&expanded_struct_info, args, template);
+    }
 
-impl CaseStyle {
-    fn parse(s: &str) -> Option<Self> {
-        match s {
-            "snake_case" => Some(CaseStyle::Snake),
-            "camelCase" => Some(CaseStyle::Camel),
-            "PascalCase" => Some(CaseStyle::Pascal),
-            "kebab-case" => Some(CaseStyle::Kebab),
-            "SCREAMING_SNAKE_CASE" => Some(CaseStyle::ScreamingSnake),
-            "SCREAMING-KEBAB-CASE" => Some(CaseStyle::ScreamingKebab),
-            _ => None,
-        }
+    let markdown_table = generate_markdown_table(&expanded_struct_info, args)?;
+
+    // Markers would not parse as dotenv lines, the template is the whole file
+    if args.format == OutputFormat::Dotenv {
+        write_whole_file(&args.target, &markdown_table, args)?;
+    } else {
+        let markers = Markers::new(args, &expanded_struct_info.name);
+        update_target_file(&args.target, &markdown_table, &markers, args)?;
+    }
+    record_written_path(&expanded_struct_info.name, &args.target, args);
+
+    if let Some(checksum_target) = &args.emit_checksum {
+        write_checksum_file(&markdown_table, args, checksum_target)?;
     }
+
+    Ok(())
 }
 
-/// Parse struct information including fields and clap attributes
-fn parse_struct_info(input: &DeriveInput) -> syn::Result<StructInfo> {
-    let struct_name = input.ident.to_string();
+/// Expand nested structs and keep the fields selected by `field_filter`
+fn prepare_fields(struct_info: StructInfo, args: &ConfigDocsArgs) -> syn::Result<StructInfo> {
+    let mut expanded_struct_info = expand_nested_structs(struct_info, args)?;
 
-    let clap_rename_all = parse_struct_clap_attrs(&input.attrs)?;
+    if let Some(filter) = &args.field_filter {
+        expanded_struct_info
+            .fields
+            .retain(|field| glob_match(filter, &field.ident));
+    }
 
-    let fields = match &input.data {
-        Data::Struct(DataStruct {
-            fields: Fields::Named(fields),
-            ..
-        }) => {
-            let mut field_infos = Vec::new();
-            for field in &fields.named {
-                let field_info = parse_field_info(field, &struct_name)?;
-                field_infos.push(field_info);
-            }
-            field_infos
-        }
-        _ => {
-            return Err(syn::Error::new_spanned(
-                input,
-                "only named struct fields are supported",
-            ))
-        }
-    };
+    if is_strict(args) {
+        check_unknown_attrs(&expanded_struct_info)?;
+    }
 
-    Ok(StructInfo {
-        name: struct_name,
-        fields,
-        clap_rename_all,
-    })
+    if args.require_env {
+        check_env_bindings(&expanded_struct_info)?;
+    }
+
+    sort_fields(&mut expanded_struct_info, args);
+
+    Ok(expanded_struct_info)
 }
 
-/// Parse struct-level rename_all clap attribute
-fn parse_struct_clap_attrs(attrs: &[Attribute]) -> syn::Result<Option<CaseStyle>> {
-    let mut rename_all = None;
+/// Apply `sort` and `sort_groups`, keeping the fields of a group together
+///
+/// Sorting is stable, so fields with the same name keep their declaration order.
+fn sort_fields(struct_info: &mut StructInfo, args: &ConfigDocsArgs) {
+    if args.sort == SortOrder::Declaration && !args.sort_groups {
+        return;
+    }
 
-    for attr in attrs {
-        if attr.path().is_ident("clap") {
-            if let Meta::List(list) = &attr.meta {
-                let tokens = &list.tokens;
-                let tokens_str = tokens.to_string();
-                if tokens_str.contains("rename_all") {
-                    if let Some(start) = tokens_str.find("rename_all = \"") {
-                        let start = start + "rename_all = \"".len();
-                        if let Some(end) = tokens_str[start..].find('"') {
-                            rename_all = CaseStyle::parse(&tokens_str[start..start + end]);
-                        }
-                    }
-                }
-            }
+    let mut groups: Vec<String> = Vec::new();
+    for field in &struct_info.fields {
+        if !groups.contains(&field.group) {
+            groups.push(field.group.clone());
         }
     }
+    if args.sort_groups {
+        groups.sort();
+    }
 
-    Ok(rename_all)
+    let sorted_by_name = args.sort == SortOrder::Alpha;
+    let info = struct_info.clone();
+    struct_info.fields.sort_by_cached_key(|field| {
+        let group = groups.iter().position(|group| *group == field.group);
+        // Env-only tables are named by their variables
+        let name = match sorted_by_name {
+            false => String::new(),
+            true if info.doc_attrs.env_only => resolve_env_name(field, &info).unwrap_or_default(),
+            true => field.display_name(),
+        };
+        (group, name)
+    });
 }
 
-/// Parse individual field information
-fn parse_field_info(field: &Field, parent_struct: &str) -> syn::Result<FieldInfo> {
-    let field_name = field.ident.as_ref().unwrap().to_string();
-    let field_type = type_to_string(&field.ty);
-    let doc_comment = extract_doc_comment(&field.attrs);
-    let clap_attrs = parse_field_clap_attrs(&field.attrs)?;
+/// Render a registered struct's documentation for `render!`
+fn render_docs(struct_name: &syn::Ident, args: &ConfigDocsArgs) -> syn::Result<String> {
+    validate_render_args(args)?;
 
-    let group = if clap_attrs.flatten {
-        extract_type_name(&field.ty).unwrap_or_else(|| "Unknown".to_string())
-    } else {
-        parent_struct.to_string()
-    };
+    let struct_info = get_registered_struct(&struct_name.to_string()).ok_or_else(|| {
+        syn::Error::new_spanned(
+            struct_name,
+            format!("{struct_name} is not registered with #[register]"),
+        )
+    })?;
+    let struct_info = merge_structs(struct_info, args);
+    if !can_generate_immediately(&struct_info)? {
+        return Err(syn::Error::new_spanned(
+            struct_name,
+            format!("the nested structs of {struct_name} are not all registered yet"),
+        ));
+    }
 
-    Ok(FieldInfo {
-        name: field_name,
-        field_type,
-        doc_comment,
-        clap_attrs,
-        group,
-    })
+    generate_markdown_table(&prepare_fields(struct_info, args)?, args)
 }
 
-/// Parse clap attributes for a field
-fn parse_field_clap_attrs(attrs: &[Attribute]) -> syn::Result<ClapAttrs> {
-    let mut clap_attrs = ClapAttrs::default();
-    
-    for attr in attrs {
-        if attr.path().is_ident("clap") {
-            match &attr.meta {
-                Meta::List(list) => {
-                    parse_clap_meta_list(&mut clap_attrs, list)?;
-                }
-                Meta::Path(_) => {
-                    // Handle #[clap] without arguments - currently no-op
-                }
-                Meta::NameValue(nv) => {
-                    parse_clap_name_value(&mut clap_attrs, nv)?;
-                }
-            }
-        }
+/// Write the FNV-1a hash of the generated region to `emit_checksum`, as 16 hex digits
+///
+/// The region is hashed without the blank lines around it, as found between the markers.
+fn write_checksum_file(
+    markdown_table: &str,
+    args: &ConfigDocsArgs,
+    checksum_target: &str,
+) -> syn::Result<()> {
+    let content = format!("{:016x}\n", fnv1a_64(markdown_table.trim()));
+    let file_path = resolve_target_path(checksum_target, args);
+    let _lock = lock_target(&file_path, checksum_target, args)?;
+
+    if is_check(args) {
+        let existing = fs::read_to_string(&file_path).ok();
+        return check_up_to_date(checksum_target, existing.as_deref(), &content, None, args);
     }
-    
-    Ok(clap_attrs)
+
+    write_atomically(&file_path, &content).map_err(|e| {
+        syn::Error::new(
+            Span::call_site(),
+            format!("Failed to write file {checksum_target}: {e}"),
+        )
+    })
 }
 
-/// Parse a clap meta list like #[clap(flatten, default_value = "test")]
-fn parse_clap_meta_list(attrs: &mut ClapAttrs, list: &MetaList) -> syn::Result<()> {
-    let nested_metas = darling::ast::NestedMeta::parse_meta_list(list.tokens.clone())?;
+/// 64-bit FNV-1a, simple enough to reimplement in any CI script
+fn fnv1a_64(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Write the fields that have a default to `defaults_target` as a starter TOML config
+///
+/// The whole file is generated, there are no markers.
+fn write_defaults_file(
+    struct_info: &StructInfo,
+    args: &ConfigDocsArgs,
+    defaults_target: &str,
+) -> syn::Result<()> {
+    let content = format!("{}\n", render_toml(struct_info, true));
+    write_whole_file(defaults_target, &content, args)?;
+    record_written_path(&struct_info.name, defaults_target, args);
+
+    Ok(())
+}
+
+/// Replace a generated file without markers, or compare it in check mode
+///
+/// The whole file counts as the managed region: `write_if_absent_only` keeps it once it has
+/// content, and the shrink guard compares against all of it.
+fn write_whole_file(target: &str, content: &str, args: &ConfigDocsArgs) -> syn::Result<()> {
+    let file_path = resolve_target_path(target, args);
+    let _lock = lock_target(&file_path, target, args)?;
+    let existing = fs::read_to_string(&file_path).ok();
+
+    if is_check(args) {
+        return check_up_to_date(target, existing.as_deref(), content, None, args);
+    }
+
+    let existing = existing.unwrap_or_default();
+    if args.write_if_absent_only && !existing.trim().is_empty() {
+        return Ok(());
+    }
+    check_shrink(target, &existing, content, args)?;
+
+    print_to_stdout(target, content);
+    write_atomically(&file_path, content).map_err(|e| {
+        syn::Error::new(
+            Span::call_site(),
+            format!("Failed to write file {target}: {e}"),
+        )
+    })
+}
+
+/// Write the documentation of each group to its own file, named by `split_target_template`
+///
+/// `emit_checksum` is named the same way, with one checksum per file.
+fn write_split_docs(
+    struct_info: &StructInfo,
+    args: &ConfigDocsArgs,
+    template: &str,
+) -> syn::Result<()> {
+    let mut groups: IndexMap<String, Vec<FieldInfo>> = IndexMap::new();
+    for field in &struct_info.fields {
+        groups
+            .entry(field.group.clone())
+            .or_default()
+            .push(field.clone());
+    }
+
+    for (group, fields) in groups {
+        let group_info = StructInfo {
+            fields,
+            ..struct_info.clone()
+        };
+        let markdown_table = generate_markdown_table(&group_info, args)?;
+
+        let slug = slugify(&group.to_kebab_case());
+        let path = template.replace("{group}", &slug);
+        // Check mode writes nothing, a missing directory is reported as out-of-date docs
+        if let Some(parent) = resolve_target_path(&path, args)
+            .parent()
+            .filter(|_| !is_check(args))
+        {
+            fs::create_dir_all(parent).map_err(|e| {
+                syn::Error::new(
+                    Span::call_site(),
+                    format!("Failed to create directory {}: {e}", parent.display()),
+                )
+            })?;
+        }
+
+        let markers = Markers::new(args, &struct_info.name);
+        update_target_file(&path, &markdown_table, &markers, args)?;
+        record_written_path(&struct_info.name, &path, args);
+
+        if let Some(checksum_target) = &args.emit_checksum {
+            let checksum_target = checksum_target.replace("{group}", &slug);
+            write_checksum_file(&markdown_table, args, &checksum_target)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Match a name against a glob pattern where `*` matches any run of characters and `?` one
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack = None;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = backtrack {
+            // Let the last `*` swallow one more character and retry
+            p = star_p + 1;
+            n = star_n + 1;
+            backtrack = Some((star_p, star_n + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// Remember a struct's up-to-date file for the `written_paths!` macro
+///
+/// Recorded once the file holds the documentation, whether it was just written, left as is by
+/// `write_if_absent_only` or verified in check mode. The path is resolved like the target, so
+/// it doesn't depend on the working directory of whoever reads it.
+fn record_written_path(struct_name: &str, path: &str, args: &ConfigDocsArgs) {
+    let path = resolve_target_path(path, args);
+    let path = std::env::current_dir()
+        .map(|dir| dir.join(&path))
+        .unwrap_or(path)
+        .to_string_lossy()
+        .into_owned();
+    let mut written_paths = WRITTEN_PATHS.write().unwrap();
+    let paths = written_paths.entry(struct_name.to_string()).or_default();
+
+    if !paths.contains(&path) {
+        paths.push(path);
+    }
+}
+
+/// Path of the diagnostics JSON, via `diagnostics = "..."` or `CLAP_AUTODOC_DIAGNOSTICS`
+///
+/// In diagnostics mode no documentation is written.
+fn diagnostics_path(args: &ConfigDocsArgs) -> Option<String> {
+    args.diagnostics
+        .clone()
+        .or_else(|| std::env::var("CLAP_AUTODOC_DIAGNOSTICS").ok())
+        .filter(|path| !path.is_empty())
+}
+
+/// A problem with a field that makes its documentation incomplete or misleading
+struct Issue {
+    kind: &'static str,
+    field: String,
+    message: String,
+}
+
+/// Find undocumented fields, flattened structs that are not registered and environment
+/// variables used by more than one field
+fn find_issues(struct_info: &StructInfo) -> Vec<Issue> {
+    let mut issues = Vec::new();
+    let mut env_owners: HashMap<String, String> = HashMap::new();
+
+    for field in &struct_info.fields {
+        let name = field.display_name();
+        let mut issue = |kind, message| {
+            issues.push(Issue {
+                kind,
+                field: name.clone(),
+                message,
+            });
+        };
+
+        if field.is_expanded() && get_registered_struct(&field.field_type).is_none() {
+            issue(
+                "unresolved_flatten",
+                format!("{} is not registered with #[register]", field.field_type),
+            );
+        } else if resolve_description(field).is_none() {
+            issue("undocumented", "field has no description".to_string());
+        }
+
+        if let Some(env) = resolve_env_name(field, struct_info) {
+            match env_owners.get(&env) {
+                Some(owner) => issue(
+                    "duplicate_env",
+                    format!("environment variable {env} is also used by {owner}"),
+                ),
+                None => {
+                    env_owners.insert(env, name.clone());
+                }
+            }
+        }
+    }
+
+    issues
+}
+
+/// Print the issues of a struct as one warning, listing a field per line
+///
+/// Stable proc macros cannot emit warnings, printed output is shown by cargo instead.
+fn warn_issues(struct_info: &StructInfo) {
+    let issues = find_issues(struct_info);
+    if issues.is_empty() {
+        return;
+    }
+
+    let plural = if issues.len() == 1 { "" } else { "s" };
+    let mut message = format!(
+        "warning: clap_autodoc found {} issue{plural} in {}",
+        issues.len(),
+        struct_info.name
+    );
+    for issue in &issues {
+        message.push_str(&format!(
+            "\n  - {}: {} ({})",
+            issue.field, issue.message, issue.kind
+        ));
+    }
+    eprintln!("{message}");
+}
+
+/// Write the fields parsed for a struct and issues found in them as JSON for external tools
+///
+/// The file holds an array with one entry per struct written to it in this build. Its path is
+/// resolved like the target's, and it is locked even in check mode, which it is written in.
+fn write_diagnostics(
+    struct_info: &StructInfo,
+    path: &str,
+    args: &ConfigDocsArgs,
+) -> syn::Result<()> {
+    let fields: Vec<String> = struct_info
+        .fields
+        .iter()
+        .map(|field| {
+            format!(
+                "{{\"name\": {}, \"type\": {}, \"group\": {}, \"env\": {}}}",
+                json_string(&field.display_name()),
+                json_string(&field.field_type),
+                json_string(&field.group),
+                resolve_env_name(field, struct_info)
+                    .as_deref()
+                    .map_or_else(|| "null".to_string(), json_string)
+            )
+        })
+        .collect();
+    let issues: Vec<String> = find_issues(struct_info)
+        .iter()
+        .map(|issue| {
+            format!(
+                "{{\"kind\": {}, \"field\": {}, \"message\": {}}}",
+                json_string(issue.kind),
+                json_string(&issue.field),
+                json_string(&issue.message)
+            )
+        })
+        .collect();
+
+    let entry = format!(
+        "  {{\n    \"struct\": {},\n    \"fields\": [{}],\n    \"issues\": [{}]\n  }}",
+        json_string(&struct_info.name),
+        json_list(&fields),
+        json_list(&issues)
+    );
+
+    let file_path = resolve_target_path(path, args);
+    let _lock = TargetLock::acquire(&file_path).map_err(|e| {
+        syn::Error::new(
+            Span::call_site(),
+            format!("Failed to lock file {path}: {e}"),
+        )
+    })?;
+
+    let mut diagnostics = DIAGNOSTICS.write().unwrap();
+    let entries = diagnostics.entry(path.to_string()).or_default();
+    entries.insert(struct_info.name.clone(), entry);
+    let content = format!(
+        "[\n{}\n]\n",
+        entries.values().cloned().collect::<Vec<_>>().join(",\n")
+    );
+
+    write_atomically(&file_path, &content).map_err(|e| {
+        syn::Error::new(
+            Span::call_site(),
+            format!("Failed to write diagnostics {path}: {e}"),
+        )
+    })
+}
+
+/// Render JSON values as the items of an indented array
+fn json_list(items: &[String]) -> String {
+    if items.is_empty() {
+        return String::new();
+    }
+
+    format!("\n      {}\n    ", items.join(",\n      "))
+}
+
+/// Quote and escape a string as a JSON string literal
+fn json_string(value: &str) -> String {
+    let mut json = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Whether strict mode is enabled, via `strict = true` or `CLAP_AUTODOC_STRICT=1`
+fn is_strict(args: &ConfigDocsArgs) -> bool {
+    args.strict || std::env::var("CLAP_AUTODOC_STRICT").is_ok_and(|value| value == "1")
+}
+
+/// Whether check mode is enabled, via `check = true` or `CLAP_AUTODOC_CHECK=1`
+fn is_check(args: &ConfigDocsArgs) -> bool {
+    args.check || std::env::var("CLAP_AUTODOC_CHECK").is_ok_and(|value| value == "1")
+}
+
+/// Print content written to a target between delimiters when `CLAP_AUTODOC_STDOUT=1`
+///
+/// Only content that is actually written is printed, so check mode prints nothing.
+fn print_to_stdout(target_path: &str, content: &str) {
+    if std::env::var("CLAP_AUTODOC_STDOUT").is_ok_and(|value| value == "1") {
+        println!("===== clap_autodoc: {target_path} =====");
+        println!("{}", content.trim_end());
+        println!("===== end clap_autodoc: {target_path} =====");
+    }
+}
+
+/// Fail if any field uses clap attributes that are not reflected in the documentation
+fn check_unknown_attrs(struct_info: &StructInfo) -> syn::Result<()> {
+    let unknown: Vec<String> = struct_info
+        .fields
+        .iter()
+        .flat_map(|field| {
+            field
+                .clap_attrs
+                .unknown
+                .iter()
+                .map(move |attr| format!("`{attr}` on field `{}`", field.name))
+        })
+        .collect();
+
+    if unknown.is_empty() {
+        return Ok(());
+    }
+
+    Err(syn::Error::new(
+        Span::call_site(),
+        format!(
+            "unknown clap attributes in {} (strict mode): {}",
+            struct_info.name,
+            unknown.join(", ")
+        ),
+    ))
+}
+
+/// Fail if any field has no environment variable, explicit or derived from `rename_all_env`
+///
+/// Runs after expansion, so skipped and hidden fields, which are no arguments in `--help`,
+/// are already left out.
+fn check_env_bindings(struct_info: &StructInfo) -> syn::Result<()> {
+    let missing: Vec<String> = struct_info
+        .fields
+        .iter()
+        .filter(|field| !field.is_expanded() && resolve_env_name(field, struct_info).is_none())
+        .map(|field| format!("`{}`", field.name))
+        .collect();
+
+    if missing.is_empty() {
+        return Ok(());
+    }
+
+    Err(syn::Error::new(
+        Span::call_site(),
+        format!(
+            "fields without an env binding in {} (require_env): {}",
+            struct_info.name,
+            missing.join(", ")
+        ),
+    ))
+}
+
+/// Register a struct definition in the global registry
+fn register_struct_definition(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let struct_info = parse_struct_info(input)?;
+
+    {
+        let mut registry = STRUCT_REGISTRY.write().unwrap();
+        let struct_name = struct_info.name.clone();
+        registry.insert(struct_name, struct_info);
+    }
+
+    try_process_pending_generations()?;
+
+    let output = strip_autodoc_attrs(input);
+    Ok(quote! { #output }.into())
+}
+
+/// Register the string value of a constant or static for `render_with`
+fn register_content_definition(
+    ident: &syn::Ident,
+    expr: &Expr,
+    item: proc_macro2::TokenStream,
+) -> TokenStream {
+    let content = match parse_string_value(expr) {
+        Ok(content) => content,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    {
+        let mut registry = CONTENT_REGISTRY.write().unwrap();
+        registry.insert(ident.to_string(), content);
+    }
+
+    item.into()
+}
+
+/// Register the possible values of a `ValueEnum` enum in the global registry
+fn register_enum_definition(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let possible_values = parse_enum_possible_values(input)?;
+
+    {
+        let mut registry = ENUM_REGISTRY.write().unwrap();
+        registry.insert(input.ident.to_string(), possible_values);
+    }
+
+    Ok(quote! { #input }.into())
+}
+
+/// Parse the possible value strings of an enum as clap's `ValueEnum` derive names them
+fn parse_enum_possible_values(input: &DeriveInput) -> syn::Result<Vec<PossibleValue>> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(input, "expected an enum"));
+    };
+
+    // ValueEnum variants are kebab-cased unless the enum sets its own rename_all
+    let rename_all = parse_struct_clap_attrs(&input.attrs)?
+        .rename_all
+        .or(Some(CaseStyle::Kebab));
+
+    let mut possible_values = Vec::new();
+    for variant in &data.variants {
+        let clap_attrs = parse_field_clap_attrs(&variant.attrs)?;
+        if clap_attrs.skip {
+            continue;
+        }
+
+        let name = clap_attrs.name.unwrap_or_else(|| {
+            apply_field_name_transformation(&variant.ident.to_string(), &rename_all)
+        });
+        let doc = clap_attrs
+            .help
+            .or_else(|| extract_doc_comment(&variant.attrs));
+        possible_values.push(PossibleValue {
+            variant: variant.ident.to_string(),
+            name,
+            doc,
+        });
+    }
+
+    Ok(possible_values)
+}
+
+/// A possible value of a registered `ValueEnum` enum
+#[derive(Debug, Clone)]
+struct PossibleValue {
+    /// The variant identifier, to resolve `default_value_t` expressions naming it
+    variant: String,
+    name: String,
+    /// The variant's `help` or doc comment
+    doc: Option<String>,
+}
+
+/// Remove `#[clap_autodoc(...)]` attributes, which are only meaningful to this crate
+fn strip_autodoc_attrs(input: &DeriveInput) -> DeriveInput {
+    let is_doc_attr = |attr: &Attribute| attr.path().is_ident("clap_autodoc");
+
+    let mut output = input.clone();
+    output.attrs.retain(|attr| !is_doc_attr(attr));
+
+    if let Data::Struct(data) = &mut output.data {
+        for field in data.fields.iter_mut() {
+            field.attrs.retain(|attr| !is_doc_attr(attr));
+        }
+    }
+
+    output
+}
+
+/// Check if a struct can be generated immediately (all dependencies are available)
+///
+/// Fails on a cycle through the struct itself, which would otherwise wait forever for a
+/// struct that is only registered once it is generated.
+fn can_generate_immediately(struct_info: &StructInfo) -> syn::Result<bool> {
+    let registry = STRUCT_REGISTRY.read().unwrap();
+    let mut stack = vec![struct_info.name.clone()];
+
+    dependencies_registered(struct_info, &registry, &mut stack)
+}
+
+/// Whether every struct expanded into this one is registered, at any nesting level
+///
+/// `stack` holds the structs being walked, to report cycles instead of recursing forever.
+fn dependencies_registered(
+    struct_info: &StructInfo,
+    registry: &HashMap<String, StructInfo>,
+    stack: &mut Vec<String>,
+) -> syn::Result<bool> {
+    let expanded = struct_info
+        .fields
+        .iter()
+        .filter(|field| field.is_expanded() && !field.clap_attrs.skip);
+
+    for field in expanded {
+        if stack.contains(&field.field_type) {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                format!(
+                    "circular flatten: {} -> {}",
+                    stack.join(" -> "),
+                    field.field_type
+                ),
+            ));
+        }
+
+        let Some(nested) = registry.get(&field.field_type) else {
+            return Ok(false); // Missing dependency
+        };
+
+        stack.push(field.field_type.clone());
+        let registered = dependencies_registered(nested, registry, stack)?;
+        stack.pop();
+
+        if !registered {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
+/// Try to process any pending generations that now have all dependencies available
+fn try_process_pending_generations() -> syn::Result<()> {
+    let mut file_pending = FILE_PENDING_GENERATIONS.write().unwrap();
     
-    for nested_meta in nested_metas {
-        match nested_meta {
-            darling::ast::NestedMeta::Meta(meta) => parse_clap_meta(attrs, &meta)?,
-            darling::ast::NestedMeta::Lit(lit) => {
-                return Err(syn::Error::new_spanned(
-                    lit,
-                    "unexpected literal in clap attribute"
-                ));
+    // Process each target file independently
+    for (_target_file, pending_list) in file_pending.iter_mut() {
+        let mut remaining_pending = Vec::new();
+        
+        for pending_gen in pending_list.drain(..) {
+            if can_generate_immediately(&pending_gen.struct_info)? {
+                write_docs(pending_gen.struct_info, &pending_gen.args)?;
+            } else {
+                remaining_pending.push(pending_gen);
             }
         }
+        
+        *pending_list = remaining_pending;
+    }
+    
+    // Remove empty entries to keep the map clean
+    file_pending.retain(|_, pending_list| !pending_list.is_empty());
+
+    Ok(())
+}
+
+/// Output format for the markdown table
+#[derive(Debug, Clone, PartialEq, Eq, FromMeta, Default)]
+enum OutputFormat {
+    #[darling(rename = "flat")]
+    #[default]
+    Flat,
+    #[darling(rename = "grouped")]
+    Grouped,
+    #[darling(rename = "json")]
+    Json,
+    #[darling(rename = "yaml")]
+    Yaml,
+    #[darling(rename = "summary")]
+    Summary,
+    #[darling(rename = "toml")]
+    Toml,
+    #[darling(rename = "sections")]
+    Sections,
+    #[darling(rename = "env")]
+    Env,
+    #[darling(rename = "html")]
+    Html,
+    #[darling(rename = "dotenv")]
+    Dotenv,
+    #[darling(rename = "roff")]
+    Roff,
+}
+
+/// Struct names given as an array, e.g. `merge = [DatabaseConfig, RedisConfig]`
+#[derive(Debug, Clone)]
+struct StructList(Vec<String>);
+
+impl FromMeta for StructList {
+    fn from_expr(expr: &Expr) -> darling::Result<Self> {
+        let Expr::Array(array) = expr else {
+            return Err(darling::Error::unexpected_expr_type(expr));
+        };
+
+        array
+            .elems
+            .iter()
+            .map(|elem| match elem {
+                Expr::Path(path) if path.qself.is_none() && path.path.segments.len() == 1 => {
+                    Ok(path.path.segments[0].ident.to_string())
+                }
+                elem => Err(darling::Error::custom("expected a struct name").with_span(elem)),
+            })
+            .collect::<darling::Result<_>>()
+            .map(StructList)
+    }
+}
+
+/// Directory relative target paths are resolved against
+#[derive(Debug, Clone, FromMeta, Default, PartialEq, Eq)]
+enum PathBase {
+    /// `CARGO_MANIFEST_DIR` of the crate being compiled
+    #[darling(rename = "manifest")]
+    #[default]
+    Manifest,
+    /// The working directory of the compiler, which cargo sets to the workspace root
+    #[darling(rename = "cwd")]
+    Cwd,
+}
+
+/// Rendering style for the Type column
+#[derive(Debug, Clone, FromMeta, Default, PartialEq, Eq)]
+enum TypeStyle {
+    #[darling(rename = "rust")]
+    #[default]
+    Rust,
+    #[darling(rename = "clap")]
+    Clap,
+}
+
+/// Order of fields within their group
+#[derive(Debug, Clone, FromMeta, Default, PartialEq, Eq)]
+enum SortOrder {
+    #[darling(rename = "declaration")]
+    #[default]
+    Declaration,
+    /// By rendered name, after renaming
+    #[darling(rename = "alpha")]
+    Alpha,
+}
+
+/// Numbering of table rows in a leading `#` column
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum RowNumbers {
+    #[default]
+    Off,
+    /// `number_rows = true`: each table counts from 1
+    PerTable,
+    /// `number_rows = "continuous"`: grouped sections keep counting
+    Continuous,
+}
+
+impl FromMeta for RowNumbers {
+    fn from_bool(value: bool) -> darling::Result<Self> {
+        Ok(if value {
+            RowNumbers::PerTable
+        } else {
+            RowNumbers::Off
+        })
+    }
+
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "continuous" => Ok(RowNumbers::Continuous),
+            other => Err(darling::Error::unknown_value(other)),
+        }
+    }
+}
+
+/// How group names are displayed in headings and the Group column
+#[derive(Debug, Clone, FromMeta, Default)]
+enum GroupCase {
+    #[darling(rename = "raw")]
+    #[default]
+    Raw,
+    #[darling(rename = "title")]
+    Title,
+}
+
+/// Information about a struct field
+#[derive(Debug, Clone)]
+struct FieldInfo {
+    /// The Rust identifier of the field, never transformed
+    ident: String,
+    /// The name before `rename_all`, which is only applied once, by `display_name`
+    name: String,
+    /// Name of the struct declaring the field, which stays the same when it is flattened
+    source_struct: String,
+    /// Part of the required `ArgGroup` of its struct, documented on the group's heading
+    in_required_group: bool,
+    field_type: String,
+    /// Name of the value type, with `Option` and `Vec` wrappers removed
+    value_type: Option<String>,
+    doc_comment: Option<String>,
+    clap_attrs: ClapAttrs,
+    group: String,
+    is_optional: bool,
+    is_multiple: bool,
+    /// A boolean flag taking no value, either a `bool` or parsed by a bool-ish value parser
+    is_flag: bool,
+    /// `rename_all` of the struct declaring the field, kebab-case for a nested struct without one
+    rename_all: Option<CaseStyle>,
+    /// `rename_all_env` of the struct declaring the field
+    rename_all_env: Option<CaseStyle>,
+    doc_attrs: FieldDocAttrs,
+}
+
+impl FieldInfo {
+    /// Whether the field is replaced by the fields of a registered struct
+    fn is_expanded(&self) -> bool {
+        self.clap_attrs.flatten || self.doc_attrs.expand
+    }
+
+    /// The default value shown in the table, flags default to their value when not passed
+    fn default_display(&self) -> Option<String> {
+        self.doc_attrs
+            .default_display
+            .clone()
+            .or_else(|| self.enum_default())
+            .or_else(|| self.clap_attrs.default_display())
+            .or_else(|| self.is_flag.then(|| self.flag_default().to_string()))
+    }
+
+    /// The value of a flag that is not passed, `SetFalse` flags are on by default
+    fn flag_default(&self) -> &'static str {
+        match self.clap_attrs.flag_action() {
+            Some("SetFalse") => "true",
+            _ => "false",
+        }
+    }
+
+    /// A `default_value_t` naming a variant of a registered enum, spelled as users type it
+    fn enum_default(&self) -> Option<String> {
+        if self.clap_attrs.default_value.is_some() {
+            return None;
+        }
+
+        let variant = self
+            .clap_attrs
+            .default_value_t
+            .as_deref()?
+            .rsplit("::")
+            .next()?;
+        get_registered_enum_values(self.value_type.as_deref()?)?
+            .into_iter()
+            .find(|value| value.variant == variant)
+            .map(|value| value.name)
+    }
+
+    /// Whether the field must be given, i.e. it has no value to fall back to
+    fn is_required(&self) -> bool {
+        !self.is_optional
+            && (!self.is_multiple || self.clap_attrs.required)
+            && self.default_display().is_none()
+    }
+
+    /// The argument name as clap spells it: explicit `long`, then `rename`, then `rename_all`
+    fn display_name(&self) -> String {
+        self.clap_attrs
+            .long
+            .clone()
+            .or_else(|| self.clap_attrs.rename.clone())
+            .unwrap_or_else(|| apply_field_name_transformation(&self.name, &self.rename_all))
+    }
+}
+
+/// Field-level `#[clap_autodoc(...)]` attributes
+#[derive(Debug, Clone, Default, FromMeta)]
+struct FieldDocAttrs {
+    /// Expand a registered struct type inline even without `#[clap(flatten)]`
+    #[darling(default)]
+    expand: bool,
+    /// A complete pre-formatted markdown row used instead of the generated cells
+    #[darling(default)]
+    raw_row: Option<String>,
+    /// Path of a registered string constant used as the row, like `raw_row`
+    #[darling(default)]
+    render_with: Option<String>,
+    /// Prefix prepended to the names of expanded nested fields, e.g. `db` for `--db-host`
+    #[darling(default)]
+    prefix: Option<String>,
+    /// Default shown instead of clap's, e.g. for one that depends on the platform
+    #[darling(default)]
+    default_display: Option<String>,
+}
+
+/// Clap attributes for a field 
+#[derive(Debug, Clone, Default)]
+struct ClapAttrs {
+    // Value attributes
+    default_value: Option<String>,
+    default_value_t: Option<String>,
+    default_values: Option<Vec<String>>,
+    default_missing_value: Option<String>,
+    value_delimiter: Option<char>,
+    value_parser: Option<String>,
+    /// The `action`, e.g. `clap::ArgAction::SetTrue`
+    action: Option<String>,
+    /// Placeholder of the value in help, e.g. `URL` in `--endpoint <URL>`
+    value_name: Option<String>,
+    /// Help section of the argument, used as its group
+    help_heading: Option<String>,
+    
+    // Naming attributes
+    rename: Option<String>,
+    /// Explicit `name`, used as the possible value of `ValueEnum` variants
+    name: Option<String>,
+    long: Option<String>,
+    long_flag: bool,
+    short: Option<char>,
+    /// Aliases listed by `--help`, from the `visible_` attributes
+    visible_aliases: Aliases,
+    /// Aliases clap accepts without listing them
+    hidden_aliases: Aliases,
+    /// Bare `short`, deriving the flag from the field name
+    short_flag: bool,
+    
+    // Behavioral flags
+    flatten: bool,
+    required: bool,
+    skip: bool,
+    require_value_delimiter: bool,
+    /// The value has to be attached with `=`, as in `--color=always`
+    require_equals: bool,
+    /// Hidden from help, and so from the documentation
+    hide: bool,
+    /// Collects every argument after the first value, as wrappers of other commands do
+    trailing_var_arg: bool,
+    allow_hyphen_values: bool,
+    /// A positional only accepted after a `--` separator
+    last: bool,
+    required_unless: Option<RequiredUnless>,
+    /// Ids of arguments that cannot be given together with this one
+    conflicts_with: Vec<String>,
+    /// Ids of arguments that have to be given as well when this one is
+    requires: Vec<String>,
+    /// Completion candidates added with `add`, e.g. `ArgValueCompleter`
+    custom_completion: bool,
+    num_args: Option<NumArgs>,
+    /// Bounds of a ranged integer `value_parser`
+    value_range: Option<ValueRange>,
+    
+    // Documentation attributes
+    help: Option<String>,
+    /// Keep the doc comment's line breaks and indentation
+    verbatim_doc_comment: bool,
+    long_help: Option<String>,
+    about: Option<String>,
+    
+    // Environment binding
+    env: Option<String>,
+    /// Bare `env`, deriving the variable name from the field name
+    env_flag: bool,
+
+    // Attributes not understood by this crate, reported in strict mode
+    unknown: Vec<String>,
+}
+
+/// Number of values an argument takes, from `num_args`
+#[derive(Debug, Clone, Copy)]
+struct NumArgs {
+    min: usize,
+    /// `None` for an unbounded range like `1..`
+    max: Option<usize>,
+}
+
+/// Accepted values of a ranged integer value parser, both bounds inclusive
+#[derive(Debug, Clone, Copy)]
+struct ValueRange {
+    min: Option<i128>,
+    max: Option<i128>,
+}
+
+/// Alternative long and short names of an argument
+#[derive(Debug, Clone, Default)]
+struct Aliases {
+    long: Vec<String>,
+    short: Vec<char>,
+}
+
+impl Aliases {
+    /// The names as typed, long ones verbatim followed by short ones as `-c`
+    fn names(&self) -> Vec<String> {
+        let short = self.short.iter().map(|c| format!("-{c}"));
+        self.long.iter().cloned().chain(short).collect()
+    }
+}
+
+/// Condition under which a required field may be omitted
+#[derive(Debug, Clone)]
+enum RequiredUnless {
+    Present(String),
+    PresentAny(Vec<String>),
+    PresentAll(Vec<String>),
+}
+
+impl ClapAttrs {
+    /// The `ArgAction` variant of `action`, e.g. `Append` for `ArgAction::Append`
+    fn action_variant(&self) -> Option<&str> {
+        self.action.as_deref()?.rsplit("::").next()
+    }
+
+    /// The variant of a `SetTrue` or `SetFalse` action, which make the argument a flag
+    fn flag_action(&self) -> Option<&str> {
+        let variant = self.action_variant()?;
+        matches!(variant, "SetTrue" | "SetFalse").then_some(variant)
+    }
+
+    /// The default value to document, from whichever default attribute is set
+    fn default_display(&self) -> Option<String> {
+        self.default_value
+            .clone()
+            .or_else(|| self.default_value_t.clone())
+            .or_else(|| self.default_values.as_ref().map(|values| values.join(", ")))
+    }
+}
+
+/// Information about the entire struct
+#[derive(Debug, Clone)]
+struct StructInfo {
+    name: String,
+    fields: Vec<FieldInfo>,
+    clap_rename_all: Option<CaseStyle>,
+    clap_rename_all_env: Option<CaseStyle>,
+    clap_allow_missing_positional: bool,
+    /// Program name from `#[command(name = "...")]`
+    clap_name: Option<String>,
+    /// Doc comment of the struct, rendered as an introduction
+    doc_comment: Option<String>,
+    doc_attrs: StructDocAttrs,
+}
+
+/// Struct-level clap attributes relevant for documentation
+#[derive(Debug, Clone, Default)]
+struct StructClapAttrs {
+    rename_all: Option<CaseStyle>,
+    rename_all_env: Option<CaseStyle>,
+    allow_missing_positional: bool,
+    name: Option<String>,
+    /// `#[group(required = true)]`, at least one of the struct's arguments must be given
+    group_required: bool,
+}
+
+/// Struct-level `#[clap_autodoc(...)]` attributes
+#[derive(Debug, Clone, Default, FromMeta)]
+struct StructDocAttrs {
+    /// The struct is only loaded from environment variables and has no CLI form
+    #[darling(default)]
+    env_only: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum CaseStyle {
+    Snake,
+    Camel,
+    Pascal,
+    Kebab,
+    ScreamingSnake,
+    ScreamingKebab,
+}
+
+impl CaseStyle {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "snake_case" => Some(CaseStyle::Snake),
+            "camelCase" => Some(CaseStyle::Camel),
+            "PascalCase" => Some(CaseStyle::Pascal),
+            "kebab-case" => Some(CaseStyle::Kebab),
+            "SCREAMING_SNAKE_CASE" => Some(CaseStyle::ScreamingSnake),
+            "SCREAMING-KEBAB-CASE" => Some(CaseStyle::ScreamingKebab),
+            _ => None,
+        }
+    }
+}
+
+/// Parse struct information including fields and clap attributes
+fn parse_struct_info(input: &DeriveInput) -> syn::Result<StructInfo> {
+    let struct_name = input.ident.to_string();
+
+    let struct_clap_attrs = parse_struct_clap_attrs(&input.attrs)?;
+    let doc_attrs = parse_struct_doc_attrs(&input.attrs)?;
+
+    let fields = match &input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => {
+            let mut field_infos = Vec::new();
+            for field in &fields.named {
+                let field_info = parse_field_info(field, &struct_name, &struct_clap_attrs)?;
+                field_infos.push(field_info);
+            }
+            field_infos
+        }
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "only named struct fields are supported",
+            ))
+        }
+    };
+
+    Ok(StructInfo {
+        name: struct_name,
+        fields,
+        clap_rename_all: struct_clap_attrs.rename_all,
+        clap_rename_all_env: struct_clap_attrs.rename_all_env,
+        clap_allow_missing_positional: struct_clap_attrs.allow_missing_positional,
+        clap_name: struct_clap_attrs.name,
+        doc_comment: extract_doc_comment(&input.attrs),
+        doc_attrs,
+    })
+}
+
+/// Attribute paths of clap's derive on structs and enums, `value` being used by `ValueEnum`
+const STRUCT_ATTR_PATHS: &[&str] = &["clap", "command", "value"];
+
+/// Attribute paths of clap's derive on fields and enum variants
+const FIELD_ATTR_PATHS: &[&str] = &["clap", "arg", "command", "value"];
+
+/// Whether an attribute is a clap derive attribute with one of the given paths
+fn is_clap_attr(attr: &Attribute, paths: &[&str]) -> bool {
+    paths.iter().any(|path| attr.path().is_ident(path))
+}
+
+/// Parse struct-level `rename_all`, `rename_all_env` and `allow_missing_positional` clap
+/// attributes, and whether the struct's group is required
+fn parse_struct_clap_attrs(attrs: &[Attribute]) -> syn::Result<StructClapAttrs> {
+    let mut struct_attrs = StructClapAttrs::default();
+
+    for attr in attrs {
+        // Every `Args` struct is an `ArgGroup` of its fields, configured with `#[group(...)]`
+        if attr.path().is_ident("group") {
+            if let Meta::List(list) = &attr.meta {
+                for nested_meta in parse_meta_items_lenient(list.tokens.clone()) {
+                    match nested_meta {
+                        NestedMeta::Meta(Meta::Path(path)) if path.is_ident("required") => {
+                            struct_attrs.group_required = true;
+                        }
+                        NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("required") => {
+                            struct_attrs.group_required = parse_bool_value(&nv.value);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            continue;
+        }
+
+        if is_clap_attr(attr, STRUCT_ATTR_PATHS) {
+            if let Meta::List(list) = &attr.meta {
+                for nested_meta in parse_meta_items_lenient(list.tokens.clone()) {
+                    let nv = match nested_meta {
+                        NestedMeta::Meta(Meta::NameValue(nv)) => nv,
+                        NestedMeta::Meta(Meta::Path(path))
+                            if path.is_ident("allow_missing_positional") =>
+                        {
+                            struct_attrs.allow_missing_positional = true;
+                            continue;
+                        }
+                        _ => continue,
+                    };
+
+                    if nv.path.is_ident("allow_missing_positional") {
+                        struct_attrs.allow_missing_positional = parse_bool_value(&nv.value);
+                    } else if nv.path.is_ident("name") {
+                        struct_attrs.name = Some(parse_string_value(&nv.value)?);
+                    } else if nv.path.is_ident("rename_all") {
+                        struct_attrs.rename_all = CaseStyle::parse(&parse_string_value(&nv.value)?);
+                    } else if nv.path.is_ident("rename_all_env") {
+                        struct_attrs.rename_all_env =
+                            CaseStyle::parse(&parse_string_value(&nv.value)?);
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(struct_attrs)
+}
+
+/// Parse the comma-separated items of an attribute, skipping items that are not metas
+///
+/// Struct-level clap settings can be arbitrary builder calls such as
+/// `mut_arg("verbose", |arg| arg.short('v'))`, which have no bearing on the documentation.
+fn parse_meta_items_lenient(tokens: proc_macro2::TokenStream) -> Vec<NestedMeta> {
+    let mut items = vec![proc_macro2::TokenStream::new()];
+    for token in tokens {
+        match &token {
+            proc_macro2::TokenTree::Punct(punct) if punct.as_char() == ',' => {
+                items.push(proc_macro2::TokenStream::new());
+            }
+            _ => items.last_mut().unwrap().extend([token]),
+        }
+    }
+
+    items
+        .into_iter()
+        .filter(|item| !item.is_empty())
+        .filter_map(|item| syn::parse2::<NestedMeta>(item).ok())
+        .collect()
+}
+
+/// Parse struct-level `#[clap_autodoc(...)]` attributes
+fn parse_struct_doc_attrs(attrs: &[Attribute]) -> syn::Result<StructDocAttrs> {
+    Ok(StructDocAttrs::from_list(&collect_doc_attr_metas(attrs)?)?)
+}
+
+/// Collect the arguments of all `#[clap_autodoc(...)]` attributes
+fn collect_doc_attr_metas(attrs: &[Attribute]) -> syn::Result<Vec<NestedMeta>> {
+    let mut nested_metas = Vec::new();
+
+    for attr in attrs {
+        if attr.path().is_ident("clap_autodoc") {
+            let list = attr.meta.require_list()?;
+            nested_metas.extend(NestedMeta::parse_meta_list(list.tokens.clone())?);
+        }
+    }
+
+    Ok(nested_metas)
+}
+
+/// Parse individual field information
+fn parse_field_info(
+    field: &Field,
+    parent_struct: &str,
+    struct_clap_attrs: &StructClapAttrs,
+) -> syn::Result<FieldInfo> {
+    let field_name = field.ident.as_ref().unwrap().to_string();
+    let option_inner = wrapped_type(
+        &field.ty,
+        &["Option", "std::option::Option", "core::option::Option"],
+    );
+    let is_optional = option_inner.is_some();
+    let clap_attrs = parse_field_clap_attrs(&field.attrs)?;
+    let doc_comment = if clap_attrs.verbatim_doc_comment {
+        extract_verbatim_doc_comment(&field.attrs)
+    } else {
+        extract_doc_comment(&field.attrs)
+    };
+    let vec_inner = wrapped_type(
+        option_inner.unwrap_or(&field.ty),
+        &["Vec", "std::vec::Vec", "alloc::vec::Vec"],
+    );
+    // `Option` and `Vec` only change how often the argument is passed, the value has the
+    // inner type
+    let field_type = type_to_string(vec_inner.or(option_inner).unwrap_or(&field.ty));
+    let is_multiple = vec_inner.is_some() || clap_attrs.default_values.is_some();
+    let value_type = extract_type_name(vec_inner.or(option_inner).unwrap_or(&field.ty));
+    // `Option<bool>` and `Vec<bool>` take explicit values instead of being flags
+    let is_flag = (!is_optional && !is_multiple && field_type == "bool")
+        || clap_attrs
+            .value_parser
+            .as_deref()
+            .is_some_and(is_bool_value_parser)
+        || clap_attrs.flag_action().is_some();
+    let doc_attrs = parse_field_doc_attrs(&field.attrs)?;
+
+    let group = if clap_attrs.flatten || doc_attrs.expand {
+        extract_type_name(option_inner.unwrap_or(&field.ty))
+            .unwrap_or_else(|| "Unknown".to_string())
+    } else {
+        // Arguments are grouped like clap groups them in help
+        clap_attrs
+            .help_heading
+            .clone()
+            .unwrap_or_else(|| parent_struct.to_string())
+    };
+
+    Ok(FieldInfo {
+        ident: field_name.clone(),
+        name: field_name,
+        source_struct: parent_struct.to_string(),
+        // A field moved under its own help heading is shown apart from the group
+        in_required_group: struct_clap_attrs.group_required
+            && !clap_attrs.flatten
+            && !doc_attrs.expand
+            && clap_attrs.help_heading.is_none(),
+        field_type,
+        value_type,
+        doc_comment,
+        clap_attrs,
+        group,
+        is_optional,
+        is_multiple,
+        is_flag,
+        rename_all: struct_clap_attrs.rename_all,
+        rename_all_env: struct_clap_attrs.rename_all_env,
+        doc_attrs,
+    })
+}
+
+/// Parse field-level `#[clap_autodoc(...)]` attributes
+fn parse_field_doc_attrs(attrs: &[Attribute]) -> syn::Result<FieldDocAttrs> {
+    Ok(FieldDocAttrs::from_list(&collect_doc_attr_metas(attrs)?)?)
+}
+
+/// Parse clap attributes for a field
+fn parse_field_clap_attrs(attrs: &[Attribute]) -> syn::Result<ClapAttrs> {
+    let mut clap_attrs = ClapAttrs::default();
+    
+    for attr in attrs {
+        if is_clap_attr(attr, FIELD_ATTR_PATHS) {
+            match &attr.meta {
+                Meta::List(list) => {
+                    parse_clap_meta_list(&mut clap_attrs, list)?;
+                }
+                Meta::Path(_) => {
+                    // Handle #[clap] without arguments - currently no-op
+                }
+                Meta::NameValue(nv) => {
+                    parse_clap_name_value(&mut clap_attrs, nv)?;
+                }
+            }
+        }
+    }
+    
+    Ok(clap_attrs)
+}
+
+/// Parse a clap meta list like #[clap(flatten, default_value = "test")]
+fn parse_clap_meta_list(attrs: &mut ClapAttrs, list: &MetaList) -> syn::Result<()> {
+    let nested_metas = darling::ast::NestedMeta::parse_meta_list(list.tokens.clone())?;
+    
+    for nested_meta in nested_metas {
+        match nested_meta {
+            darling::ast::NestedMeta::Meta(meta) => parse_clap_meta(attrs, &meta)?,
+            darling::ast::NestedMeta::Lit(lit) => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    "unexpected literal in clap attribute"
+                ));
+            }
+        }
+    }
+    
+    Ok(())
+}
+
+/// Parse individual clap meta items
+fn parse_clap_meta(attrs: &mut ClapAttrs, meta: &Meta) -> syn::Result<()> {
+    match meta {
+        Meta::Path(path) => parse_clap_flag(attrs, path),
+        Meta::NameValue(nv) => parse_clap_name_value_meta(attrs, nv),
+        Meta::List(list) => parse_clap_nested_list(attrs, list),
+    }
+}
+
+/// Parse clap flag attributes like `flatten`, `required`, `skip`
+fn parse_clap_flag(attrs: &mut ClapAttrs, path: &Path) -> syn::Result<()> {
+    let ident = path.get_ident().ok_or_else(|| {
+        syn::Error::new_spanned(path, "expected simple identifier")
+    })?;
+    
+    match ident.to_string().as_str() {
+        "flatten" => attrs.flatten = true,
+        "required" => attrs.required = true,
+        "skip" => attrs.skip = true,
+        "hide" => attrs.hide = true,
+        "require_value_delimiter" => attrs.require_value_delimiter = true,
+        "require_equals" => attrs.require_equals = true,
+        "trailing_var_arg" => attrs.trailing_var_arg = true,
+        "allow_hyphen_values" => attrs.allow_hyphen_values = true,
+        "last" => attrs.last = true,
+        "verbatim_doc_comment" => attrs.verbatim_doc_comment = true,
+        "long" => attrs.long_flag = true,
+        "env" => attrs.env_flag = true,
+        // Derived from the field name, like bare `long`
+        "short" => attrs.short_flag = true,
+        // The position follows the field order, nothing to record
+        "index" => {}
+        // Possible values are listed for registered enums
+        "value_enum" => {}
+        other => attrs.unknown.push(other.to_string()),
+    }
+    
+    Ok(())
+}
+
+/// Parse clap name-value attributes like `env = "VAR"`, `default_value = "test"`
+fn parse_clap_name_value_meta(attrs: &mut ClapAttrs, nv: &MetaNameValue) -> syn::Result<()> {
+    let name = nv.path.get_ident().ok_or_else(|| {
+        syn::Error::new_spanned(&nv.path, "expected simple identifier")
+    })?;
+    
+    match name.to_string().as_str() {
+        "long" => attrs.long = Some(parse_string_value(&nv.value)?),
+        "short" => attrs.short = Some(parse_char_value(&nv.value)?),
+        "num_args" => attrs.num_args = parse_num_args(&nv.value),
+        // Legacy clap 3 spelling of a fixed `num_args`
+        "number_of_values" => {
+            attrs.num_args = parse_num_args(&nv.value).filter(|n| n.max == Some(n.min))
+        }
+        "env" => attrs.env = Some(parse_string_value(&nv.value)?),
+        // Positional order does not affect the documented row
+        "index" => {}
+        "default_value" => attrs.default_value = Some(parse_string_value(&nv.value)?),
+        "default_value_t" => attrs.default_value_t = Some(parse_default_expr(&nv.value)?),
+        "default_values_t" | "default_values_os_t" => {
+            attrs.default_values = Some(parse_expr_list_value(&nv.value)?)
+        }
+        "add" => attrs.custom_completion = true,
+        "value_parser" => {
+            attrs.value_parser = Some(parse_expr_value(&nv.value)?);
+            attrs.value_range = parse_value_range(&nv.value);
+        }
+        "action" => attrs.action = Some(parse_expr_value(&nv.value)?),
+        // A computed placeholder is only known to clap, the field name stands in
+        "value_name" => attrs.value_name = parse_string_value(&nv.value).ok(),
+        // A computed heading is only known to clap, the field stays in its struct's group
+        "help_heading" => attrs.help_heading = parse_string_value(&nv.value).ok(),
+        // A computed value is only known to clap, it is left out of Details
+        "default_missing_value" => {
+            attrs.default_missing_value = parse_string_value(&nv.value).ok()
+        }
+        "help" => attrs.help = Some(parse_string_value(&nv.value)?),
+        // Computed help like `concat!(..)` is only known to clap, the doc comment stands in
+        "long_help" => attrs.long_help = parse_string_value(&nv.value).ok(),
+        "about" => attrs.about = Some(parse_string_value(&nv.value)?),
+        "rename" => attrs.rename = Some(parse_string_value(&nv.value)?),
+        // `id` is the clap 4 spelling of a field's `name`
+        "name" | "id" => attrs.name = Some(parse_string_value(&nv.value)?),
+        // Computed aliases like `aliases = ALIASES` are only known to clap, they are left out
+        "alias" | "aliases" => attrs
+            .hidden_aliases
+            .long
+            .extend(parse_string_list_value(&nv.value).unwrap_or_default()),
+        "visible_alias" | "visible_aliases" => attrs
+            .visible_aliases
+            .long
+            .extend(parse_string_list_value(&nv.value).unwrap_or_default()),
+        "short_alias" | "short_aliases" => attrs
+            .hidden_aliases
+            .short
+            .extend(parse_char_list_value(&nv.value).unwrap_or_default()),
+        "visible_short_alias" | "visible_short_aliases" => attrs
+            .visible_aliases
+            .short
+            .extend(parse_char_list_value(&nv.value).unwrap_or_default()),
+        // A computed delimiter is only known to clap, it is left out of Details
+        "value_delimiter" => attrs.value_delimiter = parse_char_value(&nv.value).ok(),
+        "require_value_delimiter" => attrs.require_value_delimiter = parse_bool_value(&nv.value),
+        "require_equals" => attrs.require_equals = parse_bool_value(&nv.value),
+        "required" => attrs.required = parse_bool_value(&nv.value),
+        "hide" => attrs.hide = parse_bool_value(&nv.value),
+        "trailing_var_arg" => attrs.trailing_var_arg = parse_bool_value(&nv.value),
+        "allow_hyphen_values" => attrs.allow_hyphen_values = parse_bool_value(&nv.value),
+        "last" => attrs.last = parse_bool_value(&nv.value),
+        // Computed ids are only known to clap, the condition is left out
+        "required_unless_present" => {
+            attrs.required_unless = parse_string_value(&nv.value)
+                .ok()
+                .map(RequiredUnless::Present)
+        }
+        "required_unless_present_any" => {
+            attrs.required_unless = parse_string_list_value(&nv.value)
+                .ok()
+                .map(RequiredUnless::PresentAny)
+        }
+        "required_unless_present_all" => {
+            attrs.required_unless = parse_string_list_value(&nv.value)
+                .ok()
+                .map(RequiredUnless::PresentAll)
+        }
+        // Computed ids like `requires = TOKEN_ID` are only known to clap, they are left out
+        "conflicts_with" | "conflicts_with_all" => attrs
+            .conflicts_with
+            .extend(parse_string_list_value(&nv.value).unwrap_or_default()),
+        "requires" => attrs
+            .requires
+            .extend(parse_string_list_value(&nv.value).unwrap_or_default()),
+        other => attrs.unknown.push(other.to_string()),
+    }
+    
+    Ok(())
+}
+
+/// Parse clap name-value from top-level attribute
+fn parse_clap_name_value(_attrs: &mut ClapAttrs, _nv: &MetaNameValue) -> syn::Result<()> {
+    // Handle cases like #[clap = "some_value"] if needed
+    // Currently not used by clap
+    Ok(())
+}
+
+/// Parse nested clap lists, clap's method call form like `value_hint(ValueHint::DirPath)`
+fn parse_clap_nested_list(attrs: &mut ClapAttrs, list: &MetaList) -> syn::Result<()> {
+    let name = list
+        .path
+        .get_ident()
+        .ok_or_else(|| syn::Error::new_spanned(&list.path, "expected simple identifier"))?;
+
+    match name.to_string().as_str() {
+        "add" => attrs.custom_completion = true,
+        other => attrs.unknown.push(other.to_string()),
+    }
+
+    Ok(())
+}
+
+/// Parse string literal value
+fn parse_string_value(expr: &Expr) -> syn::Result<String> {
+    match expr {
+        Expr::Lit(ExprLit { lit: Lit::Str(lit_str), .. }) => {
+            Ok(lit_str.value())
+        }
+        _ => Err(syn::Error::new_spanned(expr, "expected string literal"))
+    }
+}
+
+/// Parse a list of string literals like `["a", "b"]`, also accepting a single string
+fn parse_string_list_value(expr: &Expr) -> syn::Result<Vec<String>> {
+    match expr {
+        Expr::Array(array) => array.elems.iter().map(parse_string_value).collect(),
+        Expr::Reference(reference) => parse_string_list_value(&reference.expr),
+        _ => Ok(vec![parse_string_value(expr)?]),
+    }
+}
+
+/// Parse character literal value, given either as `'c'` or `"c"`
+fn parse_char_value(expr: &Expr) -> syn::Result<char> {
+    if let Expr::Lit(ExprLit {
+        lit: Lit::Char(lit_char),
+        ..
+    }) = expr
+    {
+        return Ok(lit_char.value());
+    }
+
+    let s = parse_string_value(expr)?;
+    let mut chars = s.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(syn::Error::new_spanned(expr, "expected single character"))
+    }
+}
+
+/// Parse a list of characters like `['a', 'b']`, also accepting a single character
+fn parse_char_list_value(expr: &Expr) -> syn::Result<Vec<char>> {
+    match expr {
+        Expr::Array(array) => array.elems.iter().map(parse_char_value).collect(),
+        Expr::Reference(reference) => parse_char_list_value(&reference.expr),
+        _ => Ok(vec![parse_char_value(expr)?]),
+    }
+}
+
+/// Parse a list expression like `vec![PathBuf::from("a")]` (for default_values_t)
+///
+/// Any other expression is spelled as a single value.
+fn parse_expr_list_value(expr: &Expr) -> syn::Result<Vec<String>> {
+    let items: Vec<Expr> = match expr {
+        Expr::Array(array) => array.elems.iter().cloned().collect(),
+        Expr::Macro(mac) if mac.mac.path.is_ident("vec") => mac
+            .mac
+            .parse_body_with(Punctuated::<Expr, syn::Token![,]>::parse_terminated)?
+            .into_iter()
+            .collect(),
+        // A computed list like `PATHS.to_vec()` is shown as written, like `default_value_t`
+        _ => return Ok(vec![parse_expr_value(expr)?]),
+    };
+
+    items.iter().map(parse_list_item_value).collect()
+}
+
+/// Parse a single list item, unwrapping conversions like `PathBuf::from("a")` or `"a".into()`
+///
+/// Numbers lose their type suffix, `1u16` is listed as `1`.
+fn parse_list_item_value(expr: &Expr) -> syn::Result<String> {
+    let inner = match expr {
+        Expr::Call(call) if call.args.len() == 1 => &call.args[0],
+        Expr::MethodCall(call) if call.args.is_empty() => &*call.receiver,
+        _ => expr,
+    };
+
+    match inner {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit_str),
+            ..
+        }) => Ok(lit_str.value()),
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit_int),
+            ..
+        }) => Ok(lit_int.base10_digits().to_string()),
+        Expr::Lit(ExprLit {
+            lit: Lit::Float(lit_float),
+            ..
+        }) => Ok(lit_float.base10_digits().to_string()),
+        Expr::Unary(unary) if matches!(unary.op, syn::UnOp::Neg(_)) => {
+            Ok(format!("-{}", parse_list_item_value(&unary.expr)?))
+        }
+        _ => parse_expr_value(expr),
+    }
+}
+
+/// Parse boolean literal value
+///
+/// A computed value like `hide = HIDE` is only known to clap, so it reads as `false`: the
+/// field is documented as not hidden, not required and so on, instead of failing the build.
+fn parse_bool_value(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Lit(ExprLit {
+            lit: Lit::Bool(lit_bool),
+            ..
+        }) if lit_bool.value
+    )
+}
+
+/// Parse expression value (for default_value_t), spelled as it would be written
+fn parse_expr_value(expr: &Expr) -> syn::Result<String> {
+    Ok(tokens_to_source(expr.to_token_stream()))
+}
+
+/// Parse a `default_value_t` expression, which is only known at build time if it uses `cfg!`
+fn parse_default_expr(expr: &Expr) -> syn::Result<String> {
+    if contains_cfg_macro(expr.to_token_stream()) {
+        return Ok("(platform-dependent)".to_string());
+    }
+    parse_expr_value(expr)
+}
+
+/// Whether tokens invoke `cfg!`, at any depth
+fn contains_cfg_macro(tokens: proc_macro2::TokenStream) -> bool {
+    use proc_macro2::TokenTree;
+
+    let trees: Vec<TokenTree> = tokens.into_iter().collect();
+    trees.iter().enumerate().any(|(i, tree)| match tree {
+        TokenTree::Ident(ident) if ident == "cfg" => {
+            matches!(trees.get(i + 1), Some(TokenTree::Punct(punct)) if punct.as_char() == '!')
+        }
+        TokenTree::Group(group) => contains_cfg_macro(group.stream()),
+        _ => false,
+    })
+}
+
+/// Join tokens without the spaces `quote!` puts around `::`, `.`, `,`, `<>` and parentheses
+///
+/// `Duration::from_secs(30)` would otherwise read `Duration :: from_secs (30)`. Angle brackets
+/// are only glued as generics, after `::` or a capitalized type name as in `Vec<u8>`, so
+/// shifts and comparisons like `1 << 4` keep their spaces. Macro calls read `format!(...)`.
+fn tokens_to_source(tokens: proc_macro2::TokenStream) -> String {
+    use proc_macro2::{Delimiter, Spacing, TokenTree};
+
+    let is_punct = |tree: Option<&TokenTree>, chars: &str| matches!(tree, Some(TokenTree::Punct(punct)) if chars.contains(punct.as_char()));
+    let is_joint = |tree: Option<&TokenTree>| matches!(tree, Some(TokenTree::Punct(punct)) if punct.spacing() == Spacing::Joint);
+
+    let trees: Vec<TokenTree> = tokens.into_iter().collect();
+    let mut source = String::new();
+    // Open generic brackets, and whether the previous token opened or closed one
+    let mut generic_depth = 0;
+    let mut prev_opened_generic = false;
+    let mut prev_closed_generic = false;
+    let mut prev_macro_bang = false;
+    for (i, tree) in trees.iter().enumerate() {
+        let prev = i.checked_sub(1).map(|i| &trees[i]);
+        let next = trees.get(i + 1);
+
+        // The second character of `::`, `<<`, `==` and other joint operators
+        let continues_punct = is_joint(prev);
+        let starts_type_path = matches!(prev, Some(TokenTree::Ident(ident))
+            if ident.to_string().starts_with(|c: char| c.is_ascii_uppercase()));
+        let opens_generic = is_punct(Some(tree), "<")
+            && !continues_punct
+            && !is_punct(next, "<=")
+            && (is_punct(prev, ":") || starts_type_path);
+        let closes_generic = is_punct(Some(tree), ">") && generic_depth > 0;
+        let macro_bang = is_punct(Some(tree), "!")
+            && matches!(prev, Some(TokenTree::Ident(_)))
+            && matches!(next, Some(TokenTree::Group(_)));
+
+        let is_call = matches!(
+            tree,
+            TokenTree::Group(group)
+                if matches!(group.delimiter(), Delimiter::Parenthesis | Delimiter::Bracket)
+        ) && matches!(prev, Some(TokenTree::Ident(_) | TokenTree::Group(_)) | None)
+            || (prev_closed_generic || prev_macro_bang) && matches!(tree, TokenTree::Group(_));
+        // A sign or reference in front of an operand, e.g. `-1` or `&x`, but not the end of a
+        // binary operator like the `&` of `&&`, which is told apart by what precedes it
+        let before_operator = (0..i.saturating_sub(1))
+            .rev()
+            .find(|&j| !is_joint(trees.get(j)));
+        let is_unary = is_punct(prev, "-!&*")
+            && !prev_macro_bang
+            && before_operator.is_none_or(|j| matches!(trees[j], TokenTree::Punct(_)));
+        // The second colon of `::`, as opposed to the one of `field: value`
+        let after_path_separator = is_punct(prev, ":")
+            && i >= 2
+            && is_punct(trees.get(i - 2), ":")
+            && is_joint(trees.get(i - 2));
+        let glued = prev.is_none()
+            || continues_punct
+            || is_call
+            || is_unary
+            || macro_bang
+            || opens_generic
+            || closes_generic
+            || prev_opened_generic
+            || after_path_separator
+            || is_punct(prev, ".")
+            || is_punct(Some(tree), ":.,")
+            || matches!(prev, Some(TokenTree::Punct(punct)) if punct.as_char() == '\'');
+        if !glued {
+            source.push(' ');
+        }
+
+        if opens_generic {
+            generic_depth += 1;
+        } else if closes_generic {
+            generic_depth -= 1;
+        }
+        prev_opened_generic = opens_generic;
+        prev_closed_generic = closes_generic;
+        prev_macro_bang = macro_bang;
+
+        match tree {
+            TokenTree::Group(group) => {
+                let inner = tokens_to_source(group.stream());
+                match group.delimiter() {
+                    Delimiter::Parenthesis => source.push_str(&format!("({inner})")),
+                    Delimiter::Bracket => source.push_str(&format!("[{inner}]")),
+                    Delimiter::Brace => source.push_str(&format!("{{ {inner} }}")),
+                    Delimiter::None => source.push_str(&inner),
+                }
+            }
+            tree => source.push_str(&tree.to_string()),
+        }
+    }
+    source
+}
+
+/// Find the bounds of a ranged value parser
+///
+/// Recognizes `value_parser!(u16).range(1..)`, `RangedU64ValueParser::new().range(1..=10)`,
+/// `RangedI64ValueParser::<i32>::from(-5..5)`, `RangedI64ValueParser` likewise, and bare
+/// ranges like `1..=10`. Bounds have to be integer literals, other expressions are not
+/// evaluated.
+fn parse_value_range(expr: &Expr) -> Option<ValueRange> {
+    let range = match expr {
+        Expr::MethodCall(call) if call.method == "range" && call.args.len() == 1 => &call.args[0],
+        Expr::MethodCall(call) => return parse_value_range(&call.receiver),
+        Expr::Call(call) if call.args.len() == 1 && is_range_constructor(&call.func) => {
+            &call.args[0]
+        }
+        Expr::Range(_) => expr,
+        _ => return None,
+    };
+    let Expr::Range(range) = range else {
+        return None;
+    };
+
+    let min = match &range.start {
+        Some(start) => Some(signed_int_literal(start)?),
+        None => None,
+    };
+    let max = match &range.end {
+        Some(end) => {
+            let end = signed_int_literal(end)?;
+            Some(match range.limits {
+                syn::RangeLimits::Closed(_) => end,
+                syn::RangeLimits::HalfOpen(_) => end - 1,
+            })
+        }
+        None => None,
+    };
+    Some(ValueRange { min, max })
+}
+
+/// Whether a called path builds a ranged value parser from its argument, e.g. `from(1..=10)`
+fn is_range_constructor(func: &Expr) -> bool {
+    let Expr::Path(path) = func else {
+        return false;
+    };
+    path.path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "from" || segment.ident == "new")
+}
+
+/// Parse an integer literal, which may be negated
+fn signed_int_literal(expr: &Expr) -> Option<i128> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse().ok(),
+        Expr::Unary(unary) if matches!(unary.op, syn::UnOp::Neg(_)) => {
+            signed_int_literal(&unary.expr).map(|n| -n)
+        }
+        Expr::Paren(paren) => signed_int_literal(&paren.expr),
+        _ => None,
+    }
+}
+
+/// Parse a literal `num_args` like `1`, `0..=1` or `1..`, other expressions are not evaluated
+///
+/// Invisible groups around expressions passed through `macro_rules!` are looked through.
+fn parse_num_args(expr: &Expr) -> Option<NumArgs> {
+    let int = |expr: &Expr| match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(lit), ..
+        }) => lit.base10_parse::<usize>().ok(),
+        _ => None,
+    };
+
+    match expr {
+        Expr::Range(range) => {
+            let min = range.start.as_deref().map_or(Some(0), int)?;
+            let max = match &range.end {
+                Some(end) => {
+                    let end = int(end)?;
+                    Some(match range.limits {
+                        syn::RangeLimits::Closed(_) => end,
+                        syn::RangeLimits::HalfOpen(_) => end.saturating_sub(1),
+                    })
+                }
+                None => None,
+            };
+            Some(NumArgs { min, max })
+        }
+        Expr::Group(group) => parse_num_args(&group.expr),
+        expr => int(expr).map(|count| NumArgs {
+            min: count,
+            max: Some(count),
+        }),
+    }
+}
+
+/// Extract documentation comment from attributes, joining all of its lines
+fn extract_doc_comment(attrs: &[Attribute]) -> Option<String> {
+    let mut paragraphs = vec![Vec::new()];
+
+    for line in doc_lines(attrs) {
+        let line = line.trim().to_string();
+        match paragraphs.last_mut() {
+            Some(paragraph) if !line.is_empty() => paragraph.push(line),
+            // An empty line ends the paragraph, like in rustdoc
+            Some(paragraph) if !paragraph.is_empty() => paragraphs.push(Vec::new()),
+            _ => {}
+        }
+    }
+
+    // Lines of a paragraph are joined with spaces, paragraphs are kept on their own lines
+    let comment = paragraphs
+        .iter()
+        .filter(|paragraph| !paragraph.is_empty())
+        .map(|paragraph| paragraph.join(" "))
+        .collect::<Vec<_>>()
+        .join("\n");
+    (!comment.is_empty()).then_some(comment)
+}
+
+/// Extract all doc comment lines as written, joined by newlines
+fn extract_verbatim_doc_comment(attrs: &[Attribute]) -> Option<String> {
+    let lines: Vec<String> = doc_lines(attrs)
+        .map(|line| {
+            // `/// text` is desugared to `doc = " text"`
+            let line = line.strip_prefix(' ').unwrap_or(&line);
+            line.trim_end().to_string()
+        })
+        .collect();
+
+    let comment = lines.join("\n").trim_matches('\n').to_string();
+    (!comment.is_empty()).then_some(comment)
+}
+
+/// The lines of `#[doc = "..."]` attributes in order, whether written as `///` or not
+///
+/// A single attribute may hold several lines, as block comments and other macros produce.
+/// Docs passed through `macro_rules!` arrive wrapped in an invisible group.
+fn doc_lines(attrs: &[Attribute]) -> impl Iterator<Item = String> + '_ {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .filter_map(|attr| {
+            let Meta::NameValue(MetaNameValue { value, .. }) = &attr.meta else {
+                return None;
+            };
+            let mut value = value;
+            while let Expr::Group(group) = value {
+                value = &group.expr;
+            }
+            match value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(lit_str),
+                    ..
+                }) => Some(lit_str.value()),
+                _ => None,
+            }
+        })
+        // Unlike `lines`, an empty `///` still yields its empty line, which ends a paragraph
+        .flat_map(|doc| {
+            doc.split('\n')
+                .map(|line| line.trim_end_matches('\r').to_string())
+                .collect::<Vec<_>>()
+        })
+}
+
+/// Render multi-line text in a table cell, keeping line breaks and leading indentation
+fn multiline_to_markdown(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            let content = line.trim_start_matches(' ');
+            let indent = line.len() - content.len();
+            format!("{}{content}", "&nbsp;".repeat(indent))
+        })
+        .collect::<Vec<_>>()
+        .join("<br>")
+}
+
+/// Best-effort check whether a `value_parser` expression parses booleans
+fn is_bool_value_parser(value_parser: &str) -> bool {
+    let value_parser: String = value_parser.split_whitespace().collect();
+    [
+        "BoolishValueParser",
+        "FalseyValueParser",
+        "BoolValueParser",
+        "value_parser!(bool)",
+    ]
+    .iter()
+    .any(|parser| value_parser.contains(parser))
+}
+
+/// Convert a Type to a string representation
+fn type_to_string(ty: &Type) -> String {
+    match ty {
+        Type::Path(TypePath { path, .. }) => path
+            .segments
+            .iter()
+            .map(|seg| seg.ident.to_string())
+            .collect::<Vec<_>>()
+            .join("::"),
+        _ => ty.to_token_stream().to_string(),
+    }
+}
+
+/// Get the generic argument of a wrapper type such as `Option<T>` or `Vec<T>`
+fn wrapped_type<'a>(ty: &'a Type, wrapper_paths: &[&str]) -> Option<&'a Type> {
+    let Type::Path(TypePath { path, .. }) = ty else {
+        return None;
+    };
+
+    let path_str = path
+        .segments
+        .iter()
+        .map(|seg| seg.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::");
+    if !wrapper_paths.contains(&path_str.as_str()) {
+        return None;
+    }
+
+    match &path.segments.last()?.arguments {
+        syn::PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+            syn::GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
+
+/// Extract the type name from a Type (for group naming)
+fn extract_type_name(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(TypePath { path, .. }) => path.segments.last().map(|seg| seg.ident.to_string()),
+        _ => None,
+    }
+}
+
+/// Generate markdown table based on struct information and format
+fn generate_markdown_table(
+    struct_info: &StructInfo,
+    config: &ConfigDocsArgs,
+) -> syn::Result<String> {
+    let mut legend = Legend::default();
+    let mut content = match config.format {
+        OutputFormat::Flat => generate_flat_table(struct_info, config, &mut legend)?,
+        OutputFormat::Grouped => generate_grouped_table(struct_info, config, &mut legend)?,
+        OutputFormat::Html => generate_html_table(struct_info, config, &mut legend)?,
+        OutputFormat::Json => return Ok(generate_json(struct_info)),
+        OutputFormat::Yaml => return Ok(generate_yaml(struct_info, config)),
+        OutputFormat::Summary => return Ok(generate_summary(struct_info, config)),
+        OutputFormat::Toml => return Ok(generate_toml(struct_info)),
+        OutputFormat::Env => return Ok(generate_env(struct_info, config)),
+        OutputFormat::Dotenv => return Ok(generate_dotenv(struct_info, config)),
+        OutputFormat::Roff => return Ok(generate_roff(struct_info, config)),
+        OutputFormat::Sections => {
+            let content = with_intro(struct_info, generate_sections(struct_info, config));
+            return Ok(with_command_title(struct_info, config, content));
+        }
+    };
+
+    if struct_info.clap_allow_missing_positional {
+        content = format!(
+            "{}\n\nNote: an optional positional argument may be omitted even when a later \
+             positional argument is given.",
+            content.trim_end()
+        );
+    }
+
+    if config.legend {
+        content = format!("{}\n\n{}", content.trim_end(), legend.render(config));
+    }
+
+    let content = with_intro(struct_info, content);
+
+    if config.collapsible {
+        // Anchor the docs to the binary name when the command sets one
+        let summary = match (&config.title, &struct_info.clap_name) {
+            (Some(title), _) => title.clone(),
+            (None, Some(name)) => format!("{name} Configuration"),
+            (None, None) => "Configuration".to_string(),
+        };
+        // GitHub only renders markdown inside <details> when separated by blank lines
+        return Ok(format!(
+            "<details>\n<summary>{summary}</summary>\n\n{}\n\n</details>",
+            content.trim_end()
+        ));
+    }
+
+    Ok(with_command_title(struct_info, config, content))
+}
+
+/// Put a title naming the program in front of markdown content, when the command sets one
+///
+/// Collapsible output names it in the `<summary>` instead.
+fn with_command_title(struct_info: &StructInfo, config: &ConfigDocsArgs, content: String) -> String {
+    match &struct_info.clap_name {
+        Some(name) => {
+            let heading = render_heading(1, &format!("{name} Configuration"), config);
+            format!("{heading}\n\n{content}")
+        }
+        None => content,
+    }
+}
+
+/// Put the struct's doc comment in front of markdown content as an introduction
+fn with_intro(struct_info: &StructInfo, content: String) -> String {
+    match &struct_info.doc_comment {
+        Some(doc) => format!("{}\n\n{content}", doc.replace('\n', "\n\n")),
+        None => content,
+    }
+}
+
+/// Serialize the fields as a fenced JSON array, keys in a fixed order for stable diffs
+fn generate_json(struct_info: &StructInfo) -> String {
+    let fields = struct_info
+        .fields
+        .iter()
+        .map(|field| {
+            let optional = |value: Option<String>| {
+                value
+                    .as_deref()
+                    .map_or_else(|| "null".to_string(), json_string)
+            };
+            let entries = [
+                ("name", json_string(&field.display_name())),
+                ("type", json_string(&field.field_type)),
+                ("required", field.is_required().to_string()),
+                ("default", optional(field.default_display())),
+                ("env", optional(resolve_env_name(field, struct_info))),
+                ("group", json_string(&field.group)),
+                (
+                    "doc",
+                    optional(resolve_description(field).map(|(doc, _)| doc)),
+                ),
+            ];
+            let entries = entries
+                .iter()
+                .map(|(key, value)| format!("    \"{key}\": {value}"))
+                .collect::<Vec<_>>()
+                .join(",\n");
+            format!("  {{\n{entries}\n  }}")
+        })
+        .collect::<Vec<_>>();
+
+    if fields.is_empty() {
+        return "```json\n[]\n```".to_string();
+    }
+
+    format!("```json\n[\n{}\n]\n```", fields.join(",\n"))
+}
+
+/// Render one line per field for quick scanning, e.g.
+/// `postgres-host — Database host [String, required, env POSTGRES_HOST]`
+fn generate_summary(struct_info: &StructInfo, config: &ConfigDocsArgs) -> String {
+    struct_info
+        .fields
+        .iter()
+        .map(|field| {
+            let mut facts = vec![render_field_type(field, config)];
+            facts.push(match field.default_display() {
+                Some(default) => format!("default {default}"),
+                None if field.is_required() => "required".to_string(),
+                None => "optional".to_string(),
+            });
+            if let Some(env) = resolve_env_name(field, struct_info) {
+                facts.push(format!("env {env}"));
+            }
+
+            let name = field.display_name();
+            let name = with_field_anchor(&name, name.clone(), config);
+            match resolve_description(field) {
+                Some((doc, _)) => format!("- {name} — {doc} [{}]", facts.join(", ")),
+                None => format!("- {name} [{}]", facts.join(", ")),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Render a heading per field, followed by its description and a list of its facts
+///
+/// Reads better than a table when options have long descriptions.
+fn generate_sections(struct_info: &StructInfo, config: &ConfigDocsArgs) -> String {
+    struct_info
+        .fields
+        .iter()
+        .map(|field| {
+            let name = match long_flag_name(field) {
+                Some(flag) => format!("--{flag}"),
+                None if struct_info.doc_attrs.env_only => {
+                    resolve_env_name(field, struct_info).unwrap_or_else(|| field.display_name())
+                }
+                None => field.display_name(),
+            };
+            let heading = with_field_anchor(&name, name.clone(), config);
+            let mut section = render_heading(3, &heading, config);
+
+            if let Some((description, _)) = resolve_description(field) {
+                // Paragraphs of the doc comment stay paragraphs
+                section.push_str(&format!("\n\n{}", description.replace('\n', "\n\n")));
+            }
+
+            let placeholder = config.empty_placeholder();
+            let default = field.default_display();
+            let env = resolve_env_name(field, struct_info);
+            let required = if field.is_required() {
+                config.required_label()
+            } else {
+                config.optional_label()
+            };
+            section.push_str(&format!(
+                "\n\n- Type: {}\n- Default: {}\n- Env: {}\n- Required: {required}",
+                render_field_type(field, config),
+                default.as_deref().unwrap_or(placeholder),
+                env.as_deref().unwrap_or(placeholder),
+            ));
+            section
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+/// Render the OPTIONS list of a man page, a `.TP` paragraph per field
+///
+/// Fields from more than one group get a `.SS` subsection per group. Flags are set in bold
+/// and values in italics, as in `.BI "\-\-port" " <PORT>"`, followed by the description and
+/// the default and environment variable the way `--help` shows them.
+fn generate_roff(struct_info: &StructInfo, config: &ConfigDocsArgs) -> String {
+    let mut groups: IndexMap<String, Vec<&FieldInfo>> = IndexMap::new();
+    for field in &struct_info.fields {
+        groups.entry(field.group.clone()).or_default().push(field);
+    }
+
+    let subsections = groups.len() > 1;
+    let mut lines = Vec::new();
+    for (group_name, fields) in &groups {
+        if subsections {
+            let group_name = render_group_name(group_name, config);
+            lines.push(format!(
+                ".SS {}",
+                roff_quoted(&format!("{group_name} Configuration"))
+            ));
+        }
+
+        for field in fields {
+            lines.push(".TP".to_string());
+            lines.push(roff_option(field, struct_info));
+
+            if let Some((description, _)) = resolve_description(field) {
+                // Paragraphs of the doc comment continue the indented paragraph
+                let paragraphs: Vec<String> = description.lines().map(roff_text).collect();
+                lines.push(paragraphs.join("\n.IP\n"));
+            }
+            // Like `--help`, flags are not shown with a default
+            if let Some(default) = field.default_display().filter(|_| !field.is_flag) {
+                lines.push(roff_text(&format!("[default: {default}]")));
+            }
+            if let Some(env) = resolve_env_name(field, struct_info) {
+                lines.push(roff_text(&format!("[env: {env}]")));
+            }
+            if let Some(values) = field
+                .value_type
+                .as_deref()
+                .and_then(get_registered_enum_values)
+            {
+                let names: Vec<String> = values.into_iter().map(|value| value.name).collect();
+                lines.push(roff_text(&format!(
+                    "[possible values: {}]",
+                    names.join(", ")
+                )));
+            }
+        }
+    }
+
+    lines.join("\n")
+}
+
+/// The tag of a field's `.TP` paragraph: the flag in bold, then its value in italics
+fn roff_option(field: &FieldInfo, struct_info: &StructInfo) -> String {
+    let name = field
+        .clap_attrs
+        .value_name
+        .clone()
+        .unwrap_or_else(|| field.name.to_shouty_snake_case());
+    let value = if field.is_multiple {
+        format!("<{name}>...")
+    } else {
+        format!("<{name}>")
+    };
+
+    match long_flag_name(field) {
+        Some(flag) if field.is_flag => format!(".B {}", roff_quoted(&format!("--{flag}"))),
+        Some(flag) => format!(
+            ".BI {} {}",
+            roff_quoted(&format!("--{flag}")),
+            roff_quoted(&format!(" {value}"))
+        ),
+        None if struct_info.doc_attrs.env_only => {
+            let env = resolve_env_name(field, struct_info).unwrap_or_else(|| field.display_name());
+            format!(".B {}", roff_quoted(&env))
+        }
+        None => format!(".I {}", roff_quoted(&value)),
+    }
+}
+
+/// Quote a macro argument, with dashes that must not be broken or turned into hyphens
+fn roff_quoted(text: &str) -> String {
+    format!(
+        "\"{}\"",
+        text.replace('\\', "\\e")
+            .replace('"', "\\(dq")
+            .replace('-', "\\-")
+    )
+}
+
+/// Escape a line of running text, which would be read as a request if it started with a
+/// `.` or `'`
+fn roff_text(line: &str) -> String {
+    let line = line.replace('\\', "\\e");
+    if line.starts_with(['.', '\'']) {
+        format!("\\&{line}")
+    } else {
+        line
+    }
+}
+
+/// Render a commented YAML skeleton with one section per group, to start a config file from
+///
+/// Each key holds its default, or `~` when it has none, below its doc comment.
+fn generate_yaml(struct_info: &StructInfo, config: &ConfigDocsArgs) -> String {
+    let mut groups: IndexMap<String, Vec<&FieldInfo>> = IndexMap::new();
+    for field in &struct_info.fields {
+        groups.entry(field.group.clone()).or_default().push(field);
+    }
+
+    let sections = groups
+        .iter()
+        .map(|(group_name, fields)| {
+            let mut section = format!("# {}", render_group_name(group_name, config));
+            for field in fields {
+                section.push('\n');
+                let doc = resolve_description(field).map(|(doc, _)| doc);
+                let default = field.default_display();
+                let mut comment = doc.unwrap_or_default();
+                if let Some(default) = &default {
+                    comment = format!("{comment} (default: {default})").trim().to_string();
+                }
+                for line in comment.lines() {
+                    section.push_str(format!("\n# {line}").trim_end());
+                }
+
+                let value = default_literal(field).unwrap_or_else(|| "~".to_string());
+                section.push_str(&format!("\n{}: {value}", field.display_name()));
+            }
+            section
+        })
+        .collect::<Vec<_>>();
+
+    format!("```yaml\n{}\n```", sections.join("\n\n"))
+}
+
+/// Render a fenced `.env` template of the fields read from environment variables
+///
+/// Variables without a default are commented out, with the expected type as a placeholder.
+fn generate_env(struct_info: &StructInfo, config: &ConfigDocsArgs) -> String {
+    format!(
+        "```sh\n{}\n```",
+        env_entries(struct_info, config, true).join("\n\n")
+    )
+}
+
+/// Render a plain `.env` file of the fields read from environment variables, to be copied
+/// as is, with variables without a default left blank
+fn generate_dotenv(struct_info: &StructInfo, config: &ConfigDocsArgs) -> String {
+    format!("{}\n", env_entries(struct_info, config, false).join("\n\n"))
+}
+
+/// The `.env` lines of each variable below its doc comment
+///
+/// A variable without a default is `VAR=`, or commented out as `# VAR=<Type>` with
+/// `placeholders`.
+fn env_entries(
+    struct_info: &StructInfo,
+    config: &ConfigDocsArgs,
+    placeholders: bool,
+) -> Vec<String> {
+    let mut variables: Vec<(String, &FieldInfo)> = struct_info
+        .fields
+        .iter()
+        .filter_map(|field| Some((resolve_env_name(field, struct_info)?, field)))
+        .collect();
+    if config.env_sort {
+        // Stable, so the first of repeated variables is kept, e.g. of a struct flattened twice
+        variables.sort_by(|(a, _), (b, _)| a.cmp(b));
+        variables.dedup_by(|(a, _), (b, _)| a == b);
+    }
+
+    variables
+        .iter()
+        .map(|(env, field)| {
+            let mut entry = String::new();
+            if let Some((doc, _)) = resolve_description(field) {
+                for line in doc.lines() {
+                    entry.push_str(format!("# {line}").trim_end());
+                    entry.push('\n');
+                }
+            }
+            entry.push_str(&match field.default_display() {
+                // Quoted where dotenv parsers would otherwise cut the value short
+                Some(default) if default.contains([' ', '#', '"', '\'']) => {
+                    format!("{env}={}", json_string(&default))
+                }
+                Some(default) => format!("{env}={default}"),
+                None if placeholders => format!("# {env}=<{}>", field.field_type),
+                None => format!("{env}="),
+            });
+            entry
+        })
+        .collect()
+}
+
+/// Render a fenced TOML config skeleton
+fn generate_toml(struct_info: &StructInfo) -> String {
+    format!("```toml\n{}\n```", render_toml(struct_info, false))
+}
+
+/// Render TOML config entries, with flattened structs as `[section]` tables
+///
+/// Each key holds its default below its doc comment. Keys without a default are
+/// commented out, with the expected type as a placeholder, or left out if `defaults_only`.
+fn render_toml(struct_info: &StructInfo, defaults_only: bool) -> String {
+    let mut groups: IndexMap<String, Vec<&FieldInfo>> = IndexMap::new();
+    // Keys after a table header belong to it, so the struct's own keys come first
+    groups.insert(struct_info.name.clone(), Vec::new());
+    for field in &struct_info.fields {
+        if defaults_only && field.default_display().is_none() {
+            continue;
+        }
+        groups.entry(field.group.clone()).or_default().push(field);
+    }
+
+    let sections = groups
+        .iter()
+        .filter(|(_, fields)| !fields.is_empty())
+        .map(|(group_name, fields)| {
+            let mut lines = Vec::new();
+            if *group_name != struct_info.name {
+                lines.push(format!("[{}]", group_name.to_snake_case()));
+            }
+
+            let entries = fields.iter().map(|field| {
+                let mut entry = String::new();
+                if let Some((doc, _)) = resolve_description(field) {
+                    for line in doc.lines() {
+                        entry.push_str(format!("# {line}").trim_end());
+                        entry.push('\n');
+                    }
+                }
+                let name = field.display_name();
+                entry.push_str(&match default_literal(field) {
+                    Some(default) => format!("{name} = {default}"),
+                    None => format!("# {name} = <{}>", field.field_type),
+                });
+                entry
+            });
+            lines.push(entries.collect::<Vec<_>>().join("\n\n"));
+            lines.join("\n")
+        })
+        .collect::<Vec<_>>();
+
+    sections.join("\n\n")
+}
+
+/// A field's default as a YAML and TOML value, an array for `default_values_t`
+fn default_literal(field: &FieldInfo) -> Option<String> {
+    let attrs = &field.clap_attrs;
+    let overridden = field.doc_attrs.default_display.is_some()
+        || attrs.default_value.is_some()
+        || attrs.default_value_t.is_some();
+    match &attrs.default_values {
+        Some(values) if !overridden => {
+            let items = values.iter().map(|value| scalar_literal(value, field));
+            Some(format!("[{}]", items.collect::<Vec<_>>().join(", ")))
+        }
+        _ => field
+            .default_display()
+            .map(|value| scalar_literal(&value, field)),
+    }
+}
+
+/// A default as a YAML and TOML value, following the field's type rather than its look
+///
+/// Integers, floats and booleans stay plain when the value parses as the type, spelled the
+/// canonical way (`0755` becomes `755`, `1.` becomes `1.0`). Anything else is quoted, so a
+/// `String` default like `"0755"` stays a string.
+fn scalar_literal(value: &str, field: &FieldInfo) -> String {
+    let plain = match field.value_type.as_deref() {
+        Some(
+            "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64"
+            | "i128" | "isize",
+        ) => value
+            .replace('_', "")
+            .parse::<i128>()
+            .ok()
+            .map(|number| number.to_string()),
+        Some("f32" | "f64") => value
+            .replace('_', "")
+            .parse::<f64>()
+            .ok()
+            .filter(|number| number.is_finite())
+            .map(|number| format!("{number:?}")),
+        Some("bool") => matches!(value, "true" | "false").then(|| value.to_string()),
+        _ => None,
+    };
+    plain.unwrap_or_else(|| json_string(value))
+}
+
+/// Render the Type column according to the configured type style
+///
+/// Clap value syntax is set as code, otherwise markdown renderers take `<HOST>` for a tag.
+fn render_field_type(field: &FieldInfo, config: &ConfigDocsArgs) -> String {
+    match config.type_style {
+        TypeStyle::Rust => {
+            let mut rendered = field.field_type.clone();
+            if let Some(value_name) = &field.clap_attrs.value_name {
+                rendered.push_str(&format!(" ({value_name})"));
+            }
+            if field.is_multiple {
+                rendered.push_str(" (multiple)");
+            }
+            rendered
+        }
+        TypeStyle::Clap if field.is_flag => "flag".to_string(),
+        TypeStyle::Clap => format!("`{}`", value_cardinality(field)),
+    }
+}
+
+/// The clap-style value syntax of a field, showing how many values it takes
+///
+/// Positionals follow clap's usage line: `<FILE>` (exactly one), `[FILE]` (zero or one),
+/// `<FILE>...` (one or more) and `[FILE]...` (zero or more). Options show the value
+/// passed after the flag, e.g. `[<PORT>]`. Like clap, `value_name` replaces the placeholder.
+/// A `last` positional includes the separator, as in `-- <ARGS>...` or `[-- <ARGS>...]`.
+/// A `require_equals` option includes its flag, as in `--color[=<COLOR>]`.
+fn value_cardinality(field: &FieldInfo) -> String {
+    let attrs = &field.clap_attrs;
+    let positional =
+        attrs.long.is_none() && !attrs.long_flag && attrs.short.is_none() && !attrs.short_flag;
+
+    let mut optional = field.is_optional || field.default_display().is_some();
+    let mut multiple = field.is_multiple;
+    // Like clap, a positional `Vec` may be empty unless it is marked `required`
+    if positional && field.is_multiple && !attrs.required {
+        optional = true;
+    }
+    if let Some(num_args) = attrs.num_args {
+        optional |= num_args.min == 0;
+        multiple = num_args.max != Some(1) && num_args.max != Some(0);
+    }
+
+    let name = attrs
+        .value_name
+        .clone()
+        .unwrap_or_else(|| field.name.to_shouty_snake_case());
+    let value = format!("<{name}>");
+    if attrs.last {
+        let value = if multiple {
+            format!("-- {value}...")
+        } else {
+            format!("-- {value}")
+        };
+        return if optional {
+            format!("[{value}]")
+        } else {
+            value
+        };
+    }
+    let optional_value = if positional {
+        format!("[{name}]")
+    } else {
+        format!("[{value}]")
+    };
+    if let Some(flag) = equals_flag(field) {
+        let value = if optional {
+            format!("{flag}[={value}]")
+        } else {
+            format!("{flag}={value}")
+        };
+        return if multiple { format!("{value}...") } else { value };
+    }
+    match (optional, multiple) {
+        (false, false) => value,
+        (true, false) => optional_value,
+        (false, true) => format!("{value}..."),
+        (true, true) => format!("{optional_value}..."),
+    }
+}
+
+/// The conventional `Default` of a field's type, shown with `infer_defaults`
+fn inferred_default(field: &FieldInfo, config: &ConfigDocsArgs) -> Option<String> {
+    if !config.infer_defaults {
+        return None;
+    }
+
+    if field.is_multiple {
+        return Some("empty".to_string());
+    }
+    if field.is_optional {
+        return Some("none".to_string());
+    }
+
+    match field.value_type.as_deref()? {
+        "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64" | "i128"
+        | "isize" => Some("0".to_string()),
+        "f32" | "f64" => Some("0.0".to_string()),
+        "String" | "PathBuf" | "OsString" => Some("\"\"".to_string()),
+        _ => None,
+    }
+}
+
+/// A column of the generated markdown table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Column {
+    FieldName,
+    Env,
+    Type,
+    Required,
+    Default,
+    Details,
+    Group,
+}
+
+impl Column {
+    /// Parse the key naming a column in arguments, e.g. `name` or `type`
+    fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "name" => Some(Column::FieldName),
+            "env" => Some(Column::Env),
+            "type" => Some(Column::Type),
+            "required" => Some(Column::Required),
+            "default" => Some(Column::Default),
+            "details" => Some(Column::Details),
+            "group" => Some(Column::Group),
+            _ => None,
+        }
+    }
+
+    /// Explanation of the column shown in the legend
+    fn description(self, config: &ConfigDocsArgs) -> String {
+        match self {
+            Column::FieldName => "name of the command-line argument".to_string(),
+            Column::Env => "environment variable the value is read from".to_string(),
+            Column::Type => "type of the value".to_string(),
+            Column::Required => format!(
+                "`{}` if the value has to be provided, `{}` if it is optional or has a default",
+                config.required_label(),
+                config.optional_label()
+            ),
+            Column::Default => "value used when the option is not set".to_string(),
+            Column::Details => "description and notes on how the value is parsed".to_string(),
+            Column::Group => "struct the field is declared in".to_string(),
+        }
+    }
+
+    fn header(self) -> &'static str {
+        match self {
+            Column::FieldName => "Field Name",
+            Column::Env => "Env",
+            Column::Type => "Type",
+            Column::Required => "Required",
+            Column::Default => "Default",
+            Column::Details => "Details",
+            Column::Group => "Group",
+        }
+    }
+}
+
+/// Columns rendered for a struct, optionally including the Group column
+///
+/// The `columns` argument replaces the default selection and order.
+fn table_columns(
+    struct_info: &StructInfo,
+    with_group: bool,
+    config: &ConfigDocsArgs,
+) -> syn::Result<Vec<Column>> {
+    if let Some(spec) = &config.columns {
+        let mut columns = parse_columns(spec)?;
+        columns.retain(|column| with_group || *column != Column::Group);
+        return Ok(columns);
+    }
+
+    let name_column = if struct_info.doc_attrs.env_only {
+        Column::Env
+    } else {
+        Column::FieldName
+    };
+
+    let mut columns = vec![name_column, Column::Type, Column::Required, Column::Default];
+    // Env-only tables already show the variable as the name
+    if name_column != Column::Env {
+        columns.push(Column::Env);
+    }
+    columns.push(Column::Details);
+    if with_group {
+        columns.push(Column::Group);
+    }
+    Ok(columns)
+}
+
+/// Render a single table cell for a field
+fn render_cell(
+    column: Column,
+    field: &FieldInfo,
+    struct_info: &StructInfo,
+    config: &ConfigDocsArgs,
+) -> String {
+    match column {
+        Column::FieldName => render_field_name(field, config),
+        Column::Env => match resolve_env_name(field, struct_info) {
+            // The anchor goes on the name column, which is the Env column of env-only tables
+            Some(env) if struct_info.doc_attrs.env_only => {
+                with_field_anchor(&env, env.clone(), config)
+            }
+            Some(env) => env,
+            None => config.empty_placeholder().to_string(),
+        },
+        Column::Type => render_field_type(field, config),
+        Column::Required => {
+            if !field.is_required() {
+                config.optional_label().to_string()
+            } else {
+                config.required_label().to_string()
+            }
+        }
+        Column::Default => match field.default_display() {
+            // An empty default would be indistinguishable from having none
+            Some(default) if default.is_empty() => "(empty)".to_string(),
+            Some(default) => escape_table_cell(&default),
+            None => match inferred_default(field, config) {
+                // Italicized to set it apart from clap defaults, Required is not affected
+                Some(inferred) => format!("*({inferred})*"),
+                None => config.empty_placeholder().to_string(),
+            },
+        },
+        Column::Details => escape_table_cell(&render_details(field, struct_info, config)),
+        Column::Group => render_group_name(&field.group, config),
+    }
+}
+
+/// Escape text for a markdown table cell, where `|` would end the cell and a newline the row
+fn escape_table_cell(text: &str) -> String {
+    text.replace('|', "\\|")
+        .replace("\r\n", " ")
+        .replace('\n', " ")
+}
+
+/// Footnotes holding the full text of truncated Details cells
+///
+/// Markdown footnotes are global to a document, so ids keep counting across tables and are
+/// prefixed with the struct name to stay unique next to other structs in the same file.
+#[derive(Debug)]
+struct Footnotes {
+    prefix: String,
+    next_id: usize,
+    pending: Vec<(String, String)>,
+}
+
+impl Footnotes {
+    fn new(struct_name: &str) -> Self {
+        Self {
+            prefix: struct_name.to_kebab_case(),
+            next_id: 0,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Add a footnote and return its reference, e.g. `[^database-config-1]`
+    fn add(&mut self, text: String) -> String {
+        self.next_id += 1;
+        let id = format!("{}-{}", self.prefix, self.next_id);
+        let reference = format!("[^{id}]");
+        self.pending.push((id, text));
+        reference
+    }
+
+    /// Render the footnotes added since the last call
+    fn take_rendered(&mut self) -> Option<String> {
+        if self.pending.is_empty() {
+            return None;
+        }
+
+        let rendered = self
+            .pending
+            .drain(..)
+            .map(|(id, text)| format!("[^{id}]: {text}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        Some(rendered)
+    }
+}
+
+/// A symbol that can appear in generated cells, explained by the legend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Symbol {
+    NoEnv,
+    NoDefault,
+    OptionalValue,
+    RepeatableValue,
+    Multiple,
+    Truncated,
+}
+
+impl Symbol {
+    fn description(self, config: &ConfigDocsArgs) -> String {
+        let empty = config.empty_placeholder();
+        match self {
+            Symbol::NoEnv => format!("`{empty}` in Env: the field has no environment variable"),
+            Symbol::NoDefault => format!("`{empty}` in Default: the field has no default value"),
+            Symbol::OptionalValue => "`[<VALUE>]`: the value may be omitted".to_string(),
+            Symbol::RepeatableValue => {
+                "`<VALUE>...`: the value may be given multiple times".to_string()
+            }
+            Symbol::Multiple => {
+                "`(multiple)` in Type: the value may be given multiple times".to_string()
+            }
+            Symbol::Truncated => {
+                "`…`: the text is truncated, see the footnote for the full text".to_string()
+            }
+        }
+    }
+}
+
+/// Columns and symbols used by the generated tables, rendered as a legend
+#[derive(Debug, Default)]
+struct Legend {
+    columns: Vec<Column>,
+    symbols: BTreeSet<Symbol>,
+}
+
+impl Legend {
+    fn add_columns(&mut self, columns: &[Column]) {
+        for column in columns {
+            if !self.columns.contains(column) {
+                self.columns.push(*column);
+            }
+        }
+    }
+
+    /// Record the symbols a rendered cell uses
+    fn add_cell(&mut self, column: Column, cell: &str, config: &ConfigDocsArgs) {
+        match column {
+            Column::Env if cell == config.empty_placeholder() => {
+                self.symbols.insert(Symbol::NoEnv);
+            }
+            Column::Default if cell == config.empty_placeholder() => {
+                self.symbols.insert(Symbol::NoDefault);
+            }
+            Column::Type if cell.ends_with("(multiple)") => {
+                self.symbols.insert(Symbol::Multiple);
+            }
+            Column::Type if config.type_style == TypeStyle::Clap => {
+                if cell.starts_with("`[") {
+                    self.symbols.insert(Symbol::OptionalValue);
+                }
+                if cell.ends_with("...`") {
+                    self.symbols.insert(Symbol::RepeatableValue);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn render(&self, config: &ConfigDocsArgs) -> String {
+        let mut lines = vec!["**Legend**".to_string(), String::new()];
+        lines.extend(self.columns.iter().map(|column| {
+            let description = column.description(config);
+            format!("- **{}**: {description}", column.header())
+        }));
+        lines.extend(
+            self.symbols
+                .iter()
+                .map(|symbol| format!("- {}", symbol.description(config))),
+        );
+        lines.join("\n")
+    }
+}
+
+/// Parse `columns`, e.g. "name,type,default,details", into the columns to render in order
+fn parse_columns(spec: &str) -> syn::Result<Vec<Column>> {
+    let mut columns = Vec::new();
+    for key in spec.split(',').map(str::trim).filter(|key| !key.is_empty()) {
+        let column = Column::from_key(key).ok_or_else(|| {
+            syn::Error::new(
+                Span::call_site(),
+                format!(
+                    "unknown column `{key}` in columns: expected name, env, type, required, \
+                     default, details or group"
+                ),
+            )
+        })?;
+        if columns.contains(&column) {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                format!("column `{key}` is listed more than once in columns"),
+            ));
+        }
+        columns.push(column);
+    }
+
+    if columns.is_empty() {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            "columns has to list at least one column, e.g. \"name,type,default,details\"",
+        ));
+    }
+
+    Ok(columns)
+}
+
+/// Parse `min_col_width`, e.g. "name:18,type:8", into minimum widths per column
+fn parse_min_col_widths(spec: &str) -> syn::Result<Vec<(Column, usize)>> {
+    let invalid = |entry: &str| {
+        syn::Error::new(
+            Span::call_site(),
+            format!(
+                "invalid min_col_width entry `{entry}`: expected `column:width` with a column \
+                 of name, env, type, required, default, details or group, e.g. \"name:18,type:8\""
+            ),
+        )
+    };
+
+    spec.split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .map(|entry| {
+            let (key, width) = entry.split_once(':').ok_or_else(|| invalid(entry))?;
+            let column = Column::from_key(key.trim()).ok_or_else(|| invalid(entry))?;
+            let width = width.trim().parse().map_err(|_| invalid(entry))?;
+            Ok((column, width))
+        })
+        .collect()
+}
+
+/// Truncate a Details cell to `details_max` characters, moving the full text to a footnote
+fn truncate_details(details: String, config: &ConfigDocsArgs, footnotes: &mut Footnotes) -> String {
+    match config.details_max {
+        Some(max) if details.chars().count() > max => {
+            let truncated: String = details.chars().take(max).collect();
+            let truncated = back_off_to_token_boundary(&truncated);
+            let reference = footnotes.add(details);
+            format!("{}…{reference}", truncated.trim_end())
+        }
+        _ => details,
+    }
+}
+
+/// Shorten a cut so it doesn't end inside markup, which would break the cell
+///
+/// A cut may not end on the `\` of an escaped `\|`, inside an HTML tag like `<br>` or an
+/// entity like `&nbsp;`, or inside a code span.
+fn back_off_to_token_boundary(text: &str) -> &str {
+    let mut cut = text;
+    loop {
+        let shorter = if let Some(shorter) = cut.strip_suffix('\\') {
+            shorter
+        } else if let Some(start) = cut.rfind('<').filter(|&start| !cut[start..].contains('>')) {
+            &cut[..start]
+        } else if let Some(start) = cut.rfind('&').filter(|&start| {
+            cut[start + 1..]
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '#')
+        }) {
+            &cut[..start]
+        } else if cut.matches('`').count() % 2 == 1 {
+            &cut[..cut.rfind('`').unwrap_or(0)]
+        } else {
+            return cut;
+        };
+        cut = shorter;
+    }
+}
+
+/// The pre-formatted row of a field, given inline by `raw_row` or by reference by `render_with`
+fn resolve_raw_row(field: &FieldInfo) -> syn::Result<Option<String>> {
+    if let Some(raw_row) = &field.doc_attrs.raw_row {
+        return Ok(Some(raw_row.clone()));
+    }
+
+    let Some(path) = &field.doc_attrs.render_with else {
+        return Ok(None);
+    };
+
+    // Constants are registered by their identifier, whatever module they are declared in
+    let name = path.rsplit("::").next().unwrap_or(path).trim();
+    let registry = CONTENT_REGISTRY.read().unwrap();
+    match registry.get(name) {
+        Some(content) => Ok(Some(content.clone())),
+        None => Err(syn::Error::new(
+            Span::call_site(),
+            format!(
+                "render_with of field `{}` references `{path}`, which is not registered; \
+                 add #[register] to the constant before the struct",
+                field.name
+            ),
+        )),
+    }
+}
+
+/// Split a `raw_row` into its cells, validating the cell count against the table
+fn parse_raw_row(raw_row: &str, field: &FieldInfo, columns: &[Column]) -> syn::Result<Vec<String>> {
+    let trimmed = raw_row.trim();
+    let trimmed = trimmed.strip_prefix('|').unwrap_or(trimmed);
+    let trimmed = trimmed.strip_suffix('|').unwrap_or(trimmed);
+    let cells: Vec<String> = trimmed
+        .split('|')
+        .map(|cell| cell.trim().to_string())
+        .collect();
+
+    if cells.len() != columns.len() {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            format!(
+                "raw_row of field `{}` has {} cells, but the table has {} columns",
+                field.name,
+                cells.len(),
+                columns.len()
+            ),
+        ));
+    }
+
+    Ok(cells)
+}
+
+/// Render a markdown table with the given columns for a list of fields
+///
+/// Footnotes for truncated cells are rendered right below the table.
+fn render_table(
+    columns: &[Column],
+    fields: &[&FieldInfo],
+    struct_info: &StructInfo,
+    config: &ConfigDocsArgs,
+    footnotes: &mut Footnotes,
+    legend: &mut Legend,
+    row_number: &mut usize,
+) -> syn::Result<String> {
+    let numbered = config.number_rows != RowNumbers::Off;
+    if config.number_rows == RowNumbers::PerTable {
+        *row_number = 0;
+    }
+
+    let mut builder = Builder::default();
+    let headers = columns.iter().map(|column| column.header());
+    builder.push_record(numbered.then_some("#").into_iter().chain(headers));
+    legend.add_columns(columns);
+
+    for field in fields {
+        *row_number += 1;
+        let mut record = Vec::new();
+        if numbered {
+            record.push(row_number.to_string());
+        }
+
+        if let Some(raw_row) = resolve_raw_row(field)? {
+            record.extend(parse_raw_row(&raw_row, field, columns)?);
+            builder.push_record(record);
+            continue;
+        }
+
+        for column in columns {
+            let cell = render_cell(*column, field, struct_info, config);
+            let cell = if *column == Column::Details {
+                let truncated = truncate_details(cell.clone(), config, footnotes);
+                if truncated != cell {
+                    legend.symbols.insert(Symbol::Truncated);
+                }
+                truncated
+            } else {
+                cell
+            };
+            legend.add_cell(*column, &cell, config);
+            record.push(cell);
+        }
+        builder.push_record(record);
+    }
+
+    let mut table = builder.build();
+    table.with(tabled::settings::Style::markdown());
+    if let Some(spec) = &config.min_col_width {
+        for (column, width) in parse_min_col_widths(spec)? {
+            if let Some(index) = columns.iter().position(|c| *c == column) {
+                let index = index + usize::from(numbered);
+                table.with(Modify::new(Columns::single(index)).with(Width::increase(width)));
+            }
+        }
+    }
+    let mut table = table.to_string();
+
+    if let Some(rendered_footnotes) = footnotes.take_rendered() {
+        table.push_str("\n\n");
+        table.push_str(&rendered_footnotes);
+    }
+
+    Ok(table)
+}
+
+/// Generate flat markdown table with Group column
+fn generate_flat_table(
+    struct_info: &StructInfo,
+    config: &ConfigDocsArgs,
+    legend: &mut Legend,
+) -> syn::Result<String> {
+    let fields: Vec<&FieldInfo> = struct_info.fields.iter().collect();
+    let columns = table_columns(struct_info, true, config)?;
+    let mut footnotes = Footnotes::new(&struct_info.name);
+
+    render_table(
+        &columns,
+        &fields,
+        struct_info,
+        config,
+        &mut footnotes,
+        legend,
+        &mut 0,
+    )
+}
+
+/// Generate an HTML table with a Group column, for docs styled or scripted beyond markdown
+///
+/// Cells are rendered from the field data rather than from the markdown cells, with text
+/// escaped and code spans set as `<code>`.
+fn generate_html_table(
+    struct_info: &StructInfo,
+    config: &ConfigDocsArgs,
+    legend: &mut Legend,
+) -> syn::Result<String> {
+    let columns = table_columns(struct_info, true, config)?;
+    legend.add_columns(&columns);
+
+    let headers: String = columns
+        .iter()
+        .map(|column| format!("<th>{}</th>", escape_html(column.header())))
+        .collect();
+    let mut rows = Vec::new();
+    for field in &struct_info.fields {
+        let cells: Vec<String> = match resolve_raw_row(field)? {
+            // Raw rows are written as markdown cells
+            Some(raw_row) => parse_raw_row(&raw_row, field, &columns)?
+                .iter()
+                .map(|cell| inline_markdown_to_html(&cell.replace("\\|", "|")))
+                .collect(),
+            None => columns
+                .iter()
+                .map(|column| {
+                    // The legend describes the symbols of the markdown cells
+                    let cell = render_cell(*column, field, struct_info, config);
+                    legend.add_cell(*column, &cell, config);
+                    render_html_cell(*column, field, struct_info, config)
+                })
+                .collect(),
+        };
+        let cells: String = cells
+            .iter()
+            .map(|cell| format!("<td>{cell}</td>"))
+            .collect();
+        let source = if config.emit_source {
+            format!(
+                " data-struct=\"{}\" data-field=\"{}\"",
+                escape_html(&field.source_struct),
+                escape_html(&field.ident)
+            )
+        } else {
+            String::new()
+        };
+        rows.push(format!("    <tr{source}>{cells}</tr>"));
+    }
+
+    Ok(format!(
+        "<table>\n  <thead>\n    <tr>{headers}</tr>\n  </thead>\n  <tbody>\n{}\n  </tbody>\n</table>",
+        rows.join("\n")
+    ))
+}
+
+/// Render a single cell of the HTML table for a field
+fn render_html_cell(
+    column: Column,
+    field: &FieldInfo,
+    struct_info: &StructInfo,
+    config: &ConfigDocsArgs,
+) -> String {
+    match column {
+        Column::FieldName => {
+            let name = escape_html(&field_name_text(field));
+            with_field_anchor(&field.display_name(), name, config)
+        }
+        Column::Env => match resolve_env_name(field, struct_info) {
+            Some(env) if struct_info.doc_attrs.env_only => {
+                with_field_anchor(&env, escape_html(&env), config)
+            }
+            Some(env) => escape_html(&env),
+            None => escape_html(config.empty_placeholder()),
+        },
+        Column::Type if config.type_style == TypeStyle::Clap && !field.is_flag => {
+            format!("<code>{}</code>", escape_html(&value_cardinality(field)))
+        }
+        Column::Type => escape_html(&render_field_type(field, config)),
+        Column::Required if field.is_required() => escape_html(config.required_label()),
+        Column::Required => escape_html(config.optional_label()),
+        Column::Default => match field.default_display() {
+            Some(default) if default.is_empty() => "(empty)".to_string(),
+            Some(default) => escape_html(&default),
+            None => match inferred_default(field, config) {
+                Some(inferred) => format!("<em>({})</em>", escape_html(&inferred)),
+                None => escape_html(config.empty_placeholder()),
+            },
+        },
+        Column::Details => render_details_with(field, struct_info, config, multiline_to_html),
+        Column::Group => escape_html(&render_group_name(&field.group, config)),
+    }
+}
+
+/// Escape text for HTML element content and attribute values
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Escape markdown-flavored text for HTML, turning backtick code spans into `<code>`
+///
+/// Backticks are kept as is when they don't pair up.
+fn inline_markdown_to_html(text: &str) -> String {
+    let pieces: Vec<&str> = text.split('`').collect();
+    if pieces.len().is_multiple_of(2) {
+        return escape_html(text);
+    }
+
+    pieces
+        .iter()
+        .enumerate()
+        .map(|(index, piece)| {
+            if index % 2 == 1 {
+                format!("<code>{}</code>", escape_html(piece))
+            } else {
+                escape_html(piece)
+            }
+        })
+        .collect()
+}
+
+/// Render multi-line text in an HTML cell, like `multiline_to_markdown` does for markdown
+fn multiline_to_html(text: &str) -> String {
+    text.lines()
+        .map(|line| {
+            let content = line.trim_start_matches(' ');
+            let indent = line.len() - content.len();
+            format!(
+                "{}{}",
+                "&nbsp;".repeat(indent),
+                inline_markdown_to_html(content)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("<br>")
+}
+
+/// Generate grouped markdown table with separate sections
+fn generate_grouped_table(
+    struct_info: &StructInfo,
+    config: &ConfigDocsArgs,
+    legend: &mut Legend,
+) -> syn::Result<String> {
+    let mut groups: IndexMap<String, Vec<&FieldInfo>> = IndexMap::new();
+
+    // Group fields by their group name
+    for field in &struct_info.fields {
+        groups.entry(field.group.clone()).or_default().push(field);
+    }
+
+    let columns = table_columns(struct_info, false, config)?;
+    let mut footnotes = Footnotes::new(&struct_info.name);
+    let mut row_number = 0;
+    let mut result = String::new();
+
+    for (group_name, fields) in groups {
+        let group_name = render_group_name(&group_name, config);
+        let required = if fields.iter().any(|field| field.in_required_group) {
+            " (required group)"
+        } else {
+            ""
+        };
+        let heading = render_heading(2, &format!("{group_name} Configuration{required}"), config);
+        result.push_str(&format!("{heading}\n\n"));
+
+        let table = render_table(
+            &columns,
+            &fields,
+            struct_info,
+            config,
+            &mut footnotes,
+            legend,
+            &mut row_number,
+        )?;
+
+        result.push_str(&table);
+        result.push_str("\n\n");
+    }
+
+    Ok(result)
+}
+
+/// Where the description text of a field comes from
+#[derive(Debug, Clone, Copy)]
+enum DescriptionSource {
+    Help,
+    DocComment,
+    LongHelp,
+}
+
+impl DescriptionSource {
+    fn marker(self) -> &'static str {
+        match self {
+            DescriptionSource::Help => "[help]",
+            DescriptionSource::DocComment => "[doc]",
+            DescriptionSource::LongHelp => "[long_help]",
+        }
+    }
+}
+
+/// Resolve a field's description: an explicit `help` overrides the doc comment,
+/// and `long_help` is only used when neither is present
+fn resolve_description(field: &FieldInfo) -> Option<(String, DescriptionSource)> {
+    let attrs = &field.clap_attrs;
+
+    attrs
+        .help
+        .clone()
+        .map(|help| (help, DescriptionSource::Help))
+        .or_else(|| {
+            field
+                .doc_comment
+                .clone()
+                .map(|doc| (doc, DescriptionSource::DocComment))
+        })
+        .or_else(|| {
+            attrs
+                .long_help
+                .clone()
+                .map(|long_help| (long_help, DescriptionSource::LongHelp))
+        })
+}
+
+/// Render the Details cell: the description followed by notes on clap behavior
+fn render_details(field: &FieldInfo, struct_info: &StructInfo, config: &ConfigDocsArgs) -> String {
+    render_details_with(field, struct_info, config, multiline_to_markdown)
+}
+
+/// Render the Details text, passing the description and each note through `render`
+///
+/// Notes are written as markdown, with values and flags in backtick code spans.
+fn render_details_with(
+    field: &FieldInfo,
+    struct_info: &StructInfo,
+    config: &ConfigDocsArgs,
+    render: fn(&str) -> String,
+) -> String {
+    let mut parts = Vec::new();
+
+    if let Some((description, source)) = resolve_description(field) {
+        parts.push(description);
+        if config.details_show_source {
+            parts.push(source.marker().to_string());
+        }
+    }
+
+    // The clap type style already spells out flags in the Type column
+    if field.is_flag && config.type_style != TypeStyle::Clap {
+        parts.push("(flag, takes no value)".to_string());
+    }
+    // Each occurrence adds its values to those of the previous ones, e.g. `-I a -I b`
+    if field.clap_attrs.action_variant() == Some("Append") {
+        parts.push("(repeatable, appends)".to_string());
+    }
+    if field.clap_attrs.last && config.type_style != TypeStyle::Clap {
+        parts.push("(passed after `--`)".to_string());
+    }
+    if let (Some(flag), false) = (equals_flag(field), config.type_style == TypeStyle::Clap) {
+        let name = field
+            .clap_attrs
+            .value_name
+            .clone()
+            .unwrap_or_else(|| field.name.to_shouty_snake_case());
+        parts.push(format!("(value attached with `=`, as in `{flag}=<{name}>`)"));
+    }
+
+    if let Some(possible_values) = field
+        .value_type
+        .as_deref()
+        .and_then(get_registered_enum_values)
+    {
+        // Separators are stronger once values carry their own descriptions
+        let separator = if possible_values.iter().any(|value| value.doc.is_some()) {
+            "; "
+        } else {
+            ", "
+        };
+        let possible_values = possible_values
+            .iter()
+            .map(|value| match &value.doc {
+                Some(doc) => format!("`{}`: {doc}", value.name),
+                None => format!("`{}`", value.name),
+            })
+            .collect::<Vec<_>>()
+            .join(separator);
+        parts.push(format!("(possible values: {possible_values})"));
+    }
+
+    if let Some(missing) = &field.clap_attrs.default_missing_value {
+        parts.push(match field.default_display() {
+            Some(default) => format!(
+                "(defaults to `{default}` when absent, `{missing}` when passed without a value)"
+            ),
+            None => format!("(`{missing}` when passed without a value)"),
+        });
+    }
+
+    let flag = |id: &String| id_to_flag(id, field, struct_info);
+    let flags = |ids: &[String]| ids.iter().map(flag).collect::<Vec<_>>().join(", ");
+
+    if let Some(condition) = &field.clap_attrs.required_unless {
+        parts.push(match condition {
+            RequiredUnless::Present(id) => format!("(required unless {} is set)", flag(id)),
+            RequiredUnless::PresentAny(ids) => {
+                format!("(required unless any of {} is set)", flags(ids))
+            }
+            RequiredUnless::PresentAll(ids) => {
+                format!("(required unless all of {} are set)", flags(ids))
+            }
+        });
+    }
+
+    let mut constraints = Vec::new();
+    if !field.clap_attrs.conflicts_with.is_empty() {
+        constraints.push(format!(
+            "conflicts with {}",
+            flags(&field.clap_attrs.conflicts_with)
+        ));
+    }
+    if !field.clap_attrs.requires.is_empty() {
+        constraints.push(format!("requires {}", flags(&field.clap_attrs.requires)));
+    }
+    if !constraints.is_empty() {
+        parts.push(format!("({})", constraints.join("; ")));
+    }
+
+    // clap resolves values as CLI > env > default, so the default only applies if both are unset
+    if let (Some(env), Some(_)) = (
+        resolve_env_name(field, struct_info),
+        field.default_display(),
+    ) {
+        parts.push(match long_flag_name(field) {
+            Some(flag) => format!("(default if neither `--{flag}` nor `{env}` is set)"),
+            None => format!("(default if `{env}` is unset)"),
+        });
+    }
+
+    if let Some(range) = field.clap_attrs.value_range {
+        match (range.min, range.max) {
+            (Some(min), Some(max)) => parts.push(format!("(between {min} and {max})")),
+            (Some(min), None) => parts.push(format!("(at least {min})")),
+            (None, Some(max)) => parts.push(format!("(at most {max})")),
+            (None, None) => {}
+        }
+    }
+
+    if let Some(note) = field.clap_attrs.num_args.and_then(num_args_note) {
+        parts.push(note);
+    }
+
+    match (
+        field.clap_attrs.trailing_var_arg,
+        field.clap_attrs.allow_hyphen_values,
+    ) {
+        (true, true) => parts.push(
+            "(captures all trailing arguments, including ones starting with `-`)".to_string(),
+        ),
+        (true, false) => parts.push("(captures all trailing arguments)".to_string()),
+        (false, true) => parts.push("(accepts values starting with `-`)".to_string()),
+        (false, false) => {}
+    }
+
+    if field.clap_attrs.custom_completion {
+        parts.push("(custom shell completion)".to_string());
+    }
+
+    if let Some(delimiter) = field.clap_attrs.value_delimiter {
+        let mut separated = format!("{} values", delimiter_description(delimiter));
+        // clap splits the variable's value the same way, so lists can be set through env
+        if let Some(env) = resolve_env_name(field, struct_info) {
+            separated.push_str(&format!(", on the command line and in `{env}`"));
+        }
+        parts.push(if field.clap_attrs.require_value_delimiter {
+            format!("(requires {separated})")
+        } else {
+            format!("({separated})")
+        });
+    }
+
+    parts
+        .iter()
+        .map(|part| render(part))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Describe how many values an argument takes, nothing for a single value
+///
+/// A value that may be omitted is already described by `default_missing_value`.
+fn num_args_note(num_args: NumArgs) -> Option<String> {
+    match (num_args.min, num_args.max) {
+        (0 | 1, Some(1)) => None,
+        (min, Some(max)) if min == max => Some(format!("(takes {min} values)")),
+        (min, Some(max)) => Some(format!("(takes {min} to {max} values)")),
+        (min, None) => Some(format!("(takes {min} or more values)")),
+    }
+}
+
+/// Describe values split by a delimiter, e.g. "comma-separated"
+fn delimiter_description(delimiter: char) -> String {
+    match delimiter {
+        ',' => "comma-separated".to_string(),
+        ';' => "semicolon-separated".to_string(),
+        ':' => "colon-separated".to_string(),
+        ' ' => "space-separated".to_string(),
+        other => format!("`{other}`-separated"),
+    }
+}
+
+/// Render the Field Name cell, listing aliases after the primary name
+fn render_field_name(field: &FieldInfo, config: &ConfigDocsArgs) -> String {
+    with_field_anchor(&field.display_name(), field_name_text(field), config)
+}
+
+/// The primary name of a field followed by its aliases, without an anchor
+///
+/// `rename_all` only applies to names derived from the field identifier. Aliases are
+/// spelled explicitly, so they are rendered verbatim, just like clap matches them.
+/// Short aliases follow as `-c`. Aliases `--help` does not list are marked as hidden, as in
+/// `db-url (aliases: database-url; hidden aliases: dsn)`.
+fn field_name_text(field: &FieldInfo) -> String {
+    let name = field.display_name();
+
+    let visible = field.clap_attrs.visible_aliases.names();
+    let hidden = field.clap_attrs.hidden_aliases.names();
+    let mut lists = Vec::new();
+    if !visible.is_empty() {
+        lists.push(format!("aliases: {}", visible.join(", ")));
+    }
+    if !hidden.is_empty() {
+        lists.push(format!("hidden aliases: {}", hidden.join(", ")));
+    }
+
+    if lists.is_empty() {
+        name
+    } else {
+        format!("{name} ({})", lists.join("; "))
+    }
+}
+
+/// The flag of the argument with clap id `id`, as referred to by `requires` and friends
+///
+/// Ids are field identifiers unless set with `id` or `name`. Arguments that are not among the
+/// documented fields are spelled with the `rename_all` of the field referring to them.
+fn id_to_flag(id: &str, field: &FieldInfo, struct_info: &StructInfo) -> String {
+    let target = struct_info.fields.iter().find(|other| {
+        other
+            .clap_attrs
+            .name
+            .as_deref()
+            .map_or(other.ident == id, |name| name == id)
+    });
+    match target {
+        Some(target) => match long_flag_name(target) {
+            Some(flag) => format!("`--{flag}`"),
+            None => format!("`<{}>`", target.name.to_shouty_snake_case()),
+        },
+        None => format!(
+            "`--{}`",
+            apply_field_name_transformation(id, &field.rename_all)
+        ),
     }
-    
-    Ok(())
 }
 
-/// Parse individual clap meta items
-fn parse_clap_meta(attrs: &mut ClapAttrs, meta: &Meta) -> syn::Result<()> {
-    match meta {
-        Meta::Path(path) => parse_clap_flag(attrs, path),
-        Meta::NameValue(nv) => parse_clap_name_value_meta(attrs, nv),
-        Meta::List(list) => parse_clap_nested_list(attrs, list),
+/// The long flag of a field without leading dashes, if it can be passed as `--flag`
+fn long_flag_name(field: &FieldInfo) -> Option<String> {
+    (field.clap_attrs.long.is_some() || field.clap_attrs.long_flag).then(|| field.display_name())
+}
+
+/// The flag a `require_equals` option is passed with, e.g. `--color`, preferring the long one
+fn equals_flag(field: &FieldInfo) -> Option<String> {
+    let attrs = &field.clap_attrs;
+    if !attrs.require_equals {
+        return None;
     }
+    if let Some(long) = long_flag_name(field) {
+        return Some(format!("--{long}"));
+    }
+    let short = attrs
+        .short
+        .or_else(|| attrs.short_flag.then(|| field.name.chars().next()).flatten())?;
+    Some(format!("-{short}"))
 }
 
-/// Parse clap flag attributes like `flatten`, `required`, `skip`
-fn parse_clap_flag(attrs: &mut ClapAttrs, path: &Path) -> syn::Result<()> {
-    let ident = path.get_ident().ok_or_else(|| {
-        syn::Error::new_spanned(path, "expected simple identifier")
-    })?;
-    
-    match ident.to_string().as_str() {
-        "flatten" => attrs.flatten = true,
-        "required" => attrs.required = true,
-        "skip" => attrs.skip = true,
-        _ => {}
+/// Prefix a cell with an HTML anchor for deep-linking when `field_anchors` is enabled
+fn with_field_anchor(name: &str, cell: String, config: &ConfigDocsArgs) -> String {
+    if config.field_anchors {
+        format!("<a id=\"cfg-{}\"></a>{cell}", slugify(name))
+    } else {
+        cell
     }
-    
-    Ok(())
 }
 
-/// Parse clap name-value attributes like `env = "VAR"`, `default_value = "test"`
-fn parse_clap_name_value_meta(attrs: &mut ClapAttrs, nv: &MetaNameValue) -> syn::Result<()> {
-    let name = nv.path.get_ident().ok_or_else(|| {
-        syn::Error::new_spanned(&nv.path, "expected simple identifier")
-    })?;
-    
-    match name.to_string().as_str() {
-        "long" => attrs.long = Some(parse_string_value(&nv.value)?),
-        "short" => attrs.short = Some(parse_char_value(&nv.value)?),
-        "env" => attrs.env = Some(parse_string_value(&nv.value)?),
-        "default_value" => attrs.default_value = Some(parse_string_value(&nv.value)?),
-        "default_value_t" => attrs.default_value_t = Some(parse_expr_value(&nv.value)?),
-        "help" => attrs.help = Some(parse_string_value(&nv.value)?),
-        "about" => attrs.about = Some(parse_string_value(&nv.value)?),
-        "rename" => attrs.rename = Some(parse_string_value(&nv.value)?),
-        _ => {}
+/// Convert a name into a lowercase, dash-separated slug usable in ids and file names
+fn slugify(name: &str) -> String {
+    let mut slug = String::new();
+
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
     }
-    
-    Ok(())
+
+    slug.trim_end_matches('-').to_string()
 }
 
-/// Parse clap name-value from top-level attribute
-fn parse_clap_name_value(_attrs: &mut ClapAttrs, _nv: &MetaNameValue) -> syn::Result<()> {
-    // Handle cases like #[clap = "some_value"] if needed
-    // Currently not used by clap
-    Ok(())
+/// Render a group name according to `group_case`
+fn render_group_name(group: &str, config: &ConfigDocsArgs) -> String {
+    match config.group_case {
+        GroupCase::Raw => group.to_string(),
+        GroupCase::Title => group.to_title_case(),
+    }
 }
 
-/// Parse nested clap lists
-fn parse_clap_nested_list(_attrs: &mut ClapAttrs, list: &MetaList) -> syn::Result<()> {
-    Err(syn::Error::new_spanned(
-        list,
-        "nested lists not supported in clap attributes"
-    ))
+/// Render a markdown heading, shifted down by `heading_offset` levels
+fn render_heading(level: usize, text: &str, config: &ConfigDocsArgs) -> String {
+    let hashes = "#".repeat((level + config.heading_offset).min(6));
+    format!("{hashes} {text}")
 }
 
-/// Parse string literal value
-fn parse_string_value(expr: &Expr) -> syn::Result<String> {
-    match expr {
-        Expr::Lit(ExprLit { lit: Lit::Str(lit_str), .. }) => {
-            Ok(lit_str.value())
-        }
-        _ => Err(syn::Error::new_spanned(expr, "expected string literal"))
+/// Resolve the environment variable name of a field
+///
+/// An explicit `env = "NAME"` always wins. Env-only structs derive a name for every
+/// other field from `rename_all_env`, defaulting to SCREAMING_SNAKE_CASE like clap does.
+fn resolve_env_name(field: &FieldInfo, struct_info: &StructInfo) -> Option<String> {
+    if let Some(env) = &field.clap_attrs.env {
+        return Some(env.clone());
+    }
+
+    if field.clap_attrs.env_flag || struct_info.doc_attrs.env_only {
+        let case = field.rename_all_env.unwrap_or(CaseStyle::ScreamingSnake);
+        return Some(apply_field_name_transformation(&field.name, &Some(case)));
     }
+
+    None
 }
 
-/// Parse character literal value
-fn parse_char_value(expr: &Expr) -> syn::Result<char> {
-    let s = parse_string_value(expr)?;
-    let mut chars = s.chars();
-    match (chars.next(), chars.next()) {
-        (Some(c), None) => Ok(c),
-        _ => Err(syn::Error::new_spanned(expr, "expected single character"))
+/// Apply field name transformation based on clap rename_all setting
+fn apply_field_name_transformation(field_name: &str, rename_all: &Option<CaseStyle>) -> String {
+    match rename_all {
+        Some(CaseStyle::Snake) => field_name.to_snake_case(),
+        Some(CaseStyle::Camel) => field_name.to_lower_camel_case(),
+        Some(CaseStyle::Pascal) => field_name.to_pascal_case(),
+        Some(CaseStyle::Kebab) => field_name.to_kebab_case(),
+        Some(CaseStyle::ScreamingKebab) => field_name.to_shouty_kebab_case(),
+        Some(CaseStyle::ScreamingSnake) => field_name.to_shouty_snake_case(),
+        None => field_name.to_owned(),
     }
 }
 
-/// Parse expression value (for default_value_t)
-fn parse_expr_value(expr: &Expr) -> syn::Result<String> {
-    Ok(quote!(#expr).to_string())
+/// Expand nested structs for flattened fields and fields marked `#[clap_autodoc(expand)]`
+///
+/// Expansion is recursive, so structs flattened into flattened structs are walked as well.
+fn expand_nested_structs(
+    struct_info: StructInfo,
+    args: &ConfigDocsArgs,
+) -> syn::Result<StructInfo> {
+    let mut stack = vec![struct_info.name.clone()];
+    let fields = expand_fields(&struct_info, None, &mut stack, args)?;
+
+    Ok(StructInfo {
+        fields,
+        ..struct_info
+    })
 }
 
+/// Expand the fields of a struct, `group` is the Group of its own fields when it is nested
+///
+/// Fields are named by the `rename_all` (and `rename_all_env`) of the struct declaring them,
+/// never by the parent's. Like clap, a struct without them gets kebab-case flags and
+/// SCREAMING_SNAKE_CASE variables, whatever its parent uses.
+///
+/// `stack` holds the structs being expanded, to report cycles instead of recursing forever.
+fn expand_fields(
+    struct_info: &StructInfo,
+    group: Option<&str>,
+    stack: &mut Vec<String>,
+    args: &ConfigDocsArgs,
+) -> syn::Result<Vec<FieldInfo>> {
+    let mut expanded_fields = Vec::new();
 
-/// Extract documentation comment from attributes
-fn extract_doc_comment(attrs: &[Attribute]) -> Option<String> {
-    for attr in attrs {
-        if attr.path().is_ident("doc") {
-            if let Meta::NameValue(MetaNameValue {
-                value: Expr::Lit(expr_lit),
-                ..
-            }) = &attr.meta
-            {
-                if let Lit::Str(lit_str) = &expr_lit.lit {
-                    let comment = lit_str.value().trim().to_string();
-                    if !comment.is_empty() {
-                        return Some(comment);
-                    }
+    let mut expansions: HashMap<&str, usize> = HashMap::new();
+    for field in &struct_info.fields {
+        if field.is_expanded() {
+            *expansions.entry(field.field_type.as_str()).or_default() += 1;
+        }
+    }
+
+    // Skipped fields are not arguments and hidden ones are left out of help, at any nesting
+    // level
+    for field in &struct_info.fields {
+        if field.clap_attrs.skip || field.clap_attrs.hide {
+            continue;
+        }
+
+        if !field.is_expanded() {
+            let mut field = field.clone();
+            // clap derives each struct's names on its own, defaulting to kebab-case flags and,
+            // through `rename_all_env` being unset, SCREAMING_SNAKE_CASE variables
+            field.rename_all = field.rename_all.or(Some(CaseStyle::Kebab));
+            // Like in --help, a field's own help_heading wins over the struct it is nested in
+            if let (Some(group), None) = (group, &field.clap_attrs.help_heading) {
+                field.group = group.to_string();
+            }
+            expanded_fields.push(field);
+            continue;
+        }
+
+        let Some(nested_struct) = get_registered_struct(&field.field_type) else {
+            let note = format!(
+                "Note: This field is flattened from {} (not registered)",
+                field.field_type
+            );
+            let mut expanded_field = field.clone();
+            expanded_field.doc_comment = Some(note);
+            expanded_fields.push(expanded_field);
+            continue;
+        };
+
+        if stack.contains(&nested_struct.name) {
+            return Err(syn::Error::new(
+                Span::call_site(),
+                format!(
+                    "circular flatten: {} -> {}",
+                    stack.join(" -> "),
+                    nested_struct.name
+                ),
+            ));
+        }
+
+        // A struct expanded more than once is told apart by the field it is expanded from,
+        // e.g. "Primary Db (DatabaseConfig)", unless its groups are merged
+        let duplicated = expansions[field.field_type.as_str()] > 1;
+        let nested_group = if duplicated && !args.merge_duplicate_groups {
+            format!("{} ({})", field.ident.to_title_case(), field.field_type)
+        } else {
+            field.field_type.clone()
+        };
+
+        stack.push(nested_struct.name.clone());
+        let nested_fields = expand_fields(&nested_struct, Some(&nested_group), stack, args)?;
+        stack.pop();
+
+        for mut expanded_field in nested_fields {
+            if let Some(prefix) = &field.doc_attrs.prefix {
+                expanded_field.name = format!("{prefix}_{}", expanded_field.name);
+                if let Some(long) = &mut expanded_field.clap_attrs.long {
+                    *long = format!("{prefix}-{long}");
+                }
+                if let Some(rename) = &mut expanded_field.clap_attrs.rename {
+                    *rename = format!("{prefix}-{rename}");
                 }
             }
+            expanded_fields.push(expanded_field);
         }
     }
-    None
+
+    Ok(expanded_fields)
 }
 
-/// Convert a Type to a string representation
-fn type_to_string(ty: &Type) -> String {
-    match ty {
-        Type::Path(TypePath { path, .. }) => path
-            .segments
-            .iter()
-            .map(|seg| seg.ident.to_string())
-            .collect::<Vec<_>>()
-            .join("::"),
-        _ => ty.to_token_stream().to_string(),
-    }
+/// Get the possible values of a registered enum
+fn get_registered_enum_values(enum_name: &str) -> Option<Vec<PossibleValue>> {
+    let registry = ENUM_REGISTRY.read().unwrap();
+    registry.get(enum_name).cloned()
 }
 
-/// Extract the type name from a Type (for group naming)
-fn extract_type_name(ty: &Type) -> Option<String> {
-    match ty {
-        Type::Path(TypePath { path, .. }) => path.segments.last().map(|seg| seg.ident.to_string()),
-        _ => None,
-    }
+fn get_registered_struct(struct_name: &str) -> Option<StructInfo> {
+    let registry = STRUCT_REGISTRY.read().unwrap();
+    registry.get(struct_name).cloned()
 }
 
-/// Generate markdown table based on struct information and format
-fn generate_markdown_table(
-    struct_info: &StructInfo,
-    config: &ConfigDocsArgs,
-) -> syn::Result<String> {
-    match config.format {
-        OutputFormat::Flat => generate_flat_table(struct_info),
-        OutputFormat::Grouped => generate_grouped_table(struct_info),
+/// Share of the previous managed region below which new content counts as a drastic shrink
+const SHRINK_THRESHOLD: f64 = 0.2;
+
+/// Guard against replacing a managed region with drastically smaller content
+///
+/// This usually means a parsing problem rather than an intended change, so it is reported as
+/// a warning, or as an error with `fail_on_shrink`.
+fn check_shrink(
+    target_path: &str,
+    existing: &str,
+    markdown_table: &str,
+    args: &ConfigDocsArgs,
+) -> syn::Result<()> {
+    let previous_len = existing.trim().chars().count();
+    let new_len = markdown_table.trim().chars().count();
+    if previous_len == 0 || new_len as f64 >= previous_len as f64 * SHRINK_THRESHOLD {
+        return Ok(());
     }
-}
 
-/// Row for flat table format
-#[derive(Tabled)]
-struct FlatTableRow {
-    #[tabled(rename = "Field Name")]
-    field_name: String,
-    #[tabled(rename = "Type")]
-    field_type: String,
-    #[tabled(rename = "Required")]
-    required: String,
-    #[tabled(rename = "Default")]
-    default: String,
-    #[tabled(rename = "Details")]
-    details: String,
-    #[tabled(rename = "Group")]
-    group: String,
+    let message = format!(
+        "generated documentation for {target_path} shrinks from {previous_len} to {new_len} \
+         characters"
+    );
+    if args.fail_on_shrink {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            format!("{message}; remove the old content to accept the change"),
+        ));
+    }
+
+    eprintln!("warning: {message}");
+    Ok(())
 }
 
-/// Generate flat markdown table with Group column
-fn generate_flat_table(struct_info: &StructInfo) -> syn::Result<String> {
-    let mut rows = Vec::new();
+/// In check mode, fail instead of writing when the target file is not up to date
+///
+/// With `check_diff`, the whole difference is also printed to stderr for CI logs. `managed`
+/// holds the lengths of the text in front of and after the markers, which both versions
+/// share, to only diff the managed region instead of the whole file.
+fn check_up_to_date(
+    target_path: &str,
+    existing: Option<&str>,
+    updated: &str,
+    managed: Option<(usize, usize)>,
+    args: &ConfigDocsArgs,
+) -> syn::Result<()> {
+    let Some(existing) = existing else {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            format!("documentation in {target_path} is out of date: the file does not exist"),
+        ));
+    };
 
-    for field in &struct_info.fields {
-        let field_name = apply_field_name_transformation(&field.name, &struct_info.clap_rename_all);
-        let required = if field.clap_attrs.default_value.is_some()
-            || field.clap_attrs.default_value_t.is_some()
-        {
-            "No".to_string()
-        } else {
-            "Yes".to_string()
+    if existing == updated {
+        return Ok(());
+    }
+
+    if args.check_diff {
+        let (old, new, first_line) = match managed {
+            Some((prefix, suffix)) => managed_region(existing, updated, prefix, suffix),
+            None => (existing, updated, 0),
         };
-        let default = field
-            .clap_attrs
-            .default_value
-            .as_ref()
-            .or(field.clap_attrs.default_value_t.as_ref())
-            .cloned()
-            .unwrap_or_else(|| "-".to_string());
-        let details = field
-            .doc_comment
-            .as_ref()
-            .unwrap_or(&"".to_string())
-            .clone();
-
-        rows.push(FlatTableRow {
-            field_name,
-            field_type: field.field_type.clone(),
-            required,
-            default,
-            details,
-            group: field.group.clone(),
-        });
+        eprint!("{}", unified_diff(target_path, old, new, first_line));
     }
 
-    let table = Table::new(rows)
-        .with(tabled::settings::Style::markdown())
-        .to_string();
+    let mut existing_lines = existing.lines();
+    let mut updated_lines = updated.lines();
+    let mut line = 1;
+    let (found, expected) = loop {
+        match (existing_lines.next(), updated_lines.next()) {
+            (found, expected) if found == expected && found.is_some() => line += 1,
+            (found, expected) => break (found.unwrap_or_default(), expected.unwrap_or_default()),
+        }
+    };
 
-    Ok(table)
+    Err(syn::Error::new(
+        Span::call_site(),
+        format!(
+            "documentation in {target_path} is out of date, first difference at line {line}:\n  \
+             found:    {found}\n  expected: {expected}\nbuild without `check` to regenerate it"
+        ),
+    ))
 }
 
-/// Row for grouped table format
-#[derive(Tabled)]
-struct GroupedTableRow {
-    #[tabled(rename = "Field Name")]
-    field_name: String,
-    #[tabled(rename = "Type")]
-    field_type: String,
-    #[tabled(rename = "Required")]
-    required: String,
-    #[tabled(rename = "Default")]
-    default: String,
-    #[tabled(rename = "Details")]
-    details: String,
+/// Lines of context shown around changes in `check_diff` output
+const DIFF_CONTEXT: usize = 3;
+
+/// Cut the lines from the start to the end marker out of the current and the generated file
+///
+/// Also returns the number of lines in front of them, the same in both files.
+fn managed_region<'a>(
+    existing: &'a str,
+    updated: &'a str,
+    prefix: usize,
+    suffix: usize,
+) -> (&'a str, &'a str, usize) {
+    let start = existing[..prefix].rfind('\n').map_or(0, |pos| pos + 1);
+    let region = |text: &'a str| {
+        let end = text.len() - suffix;
+        let end = text[end..].find('\n').map_or(text.len(), |pos| end + pos + 1);
+        &text[start..end]
+    };
+
+    (region(existing), region(updated), existing[..start].matches('\n').count())
 }
 
-/// Generate grouped markdown table with separate sections
-fn generate_grouped_table(struct_info: &StructInfo) -> syn::Result<String> {
-    let mut groups: IndexMap<String, Vec<&FieldInfo>> = IndexMap::new();
+/// A minimal unified diff between the committed and the generated content of a file
+///
+/// `old` and `new` may be a part of the file starting after `first_line` lines, which keeps
+/// the line numbers of the hunks pointing into the whole file.
+fn unified_diff(target_path: &str, old: &str, new: &str, first_line: usize) -> String {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
 
-    // Group fields by their group name
-    for field in &struct_info.fields {
-        groups.entry(field.group.clone()).or_default().push(field);
+    // lcs[i][j] is the length of the longest common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
     }
 
-    let mut result = String::new();
+    // Each line of the diff with its position in the old and new file
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            ops.push((' ', old[i], i, j));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            ops.push(('-', old[i], i, j));
+            i += 1;
+        } else {
+            ops.push(('+', new[j], i, j));
+            j += 1;
+        }
+    }
 
-    for (group_name, fields) in groups {
-        result.push_str(&format!("## {group_name} Configuration\n\n"));
+    let mut shown = vec![false; ops.len()];
+    for (k, _) in ops.iter().enumerate().filter(|(_, op)| op.0 != ' ') {
+        let end = (k + DIFF_CONTEXT + 1).min(ops.len());
+        shown[k.saturating_sub(DIFF_CONTEXT)..end].fill(true);
+    }
 
-        let mut rows = Vec::new();
+    let mut diff = format!("--- {target_path} (current)\n+++ {target_path} (generated)\n");
+    let mut k = 0;
+    while k < ops.len() {
+        if !shown[k] {
+            k += 1;
+            continue;
+        }
 
-        for field in fields {
-            let field_name =
-                apply_field_name_transformation(&field.name, &struct_info.clap_rename_all);
-            let required = if field.clap_attrs.default_value.is_some()
-                || field.clap_attrs.default_value_t.is_some()
-            {
-                "No".to_string()
-            } else {
-                "Yes".to_string()
-            };
-            let default = field
-                .clap_attrs
-                .default_value
-                .as_ref()
-                .or(field.clap_attrs.default_value_t.as_ref())
-                .cloned()
-                .unwrap_or_else(|| "-".to_string());
-            let details = field
-                .doc_comment
-                .as_ref()
-                .unwrap_or(&"".to_string())
-                .clone();
-
-            rows.push(GroupedTableRow {
-                field_name,
-                field_type: field.field_type.clone(),
-                required,
-                default,
-                details,
-            });
+        let start = k;
+        while k < ops.len() && shown[k] {
+            k += 1;
         }
+        let hunk = &ops[start..k];
+        let old_count = hunk.iter().filter(|op| op.0 != '+').count();
+        let new_count = hunk.iter().filter(|op| op.0 != '-').count();
+        let (_, _, old_start, new_start) = hunk[0];
+        diff.push_str(&format!(
+            "@@ -{},{old_count} +{},{new_count} @@\n",
+            first_line + old_start + 1,
+            first_line + new_start + 1
+        ));
+        for (tag, line, _, _) in hunk {
+            diff.push_str(&format!("{tag}{line}\n"));
+        }
+    }
+    diff
+}
 
-        let table = Table::new(rows)
-            .with(tabled::settings::Style::markdown())
-            .to_string();
+/// Comment markers delimiting the managed region of a target file
+#[derive(Debug, Clone)]
+struct Markers {
+    start: String,
+    end: String,
+    /// Between the markers and the content, blank lines except in roff, which renders them
+    separator: &'static str,
+}
 
-        result.push_str(&table);
-        result.push_str("\n\n");
+impl Markers {
+    /// Markers for a struct, named by `marker` and keyed by crate and struct name with
+    /// `isolate_section`
+    ///
+    /// Each crate of a workspace is built by its own rustc process, so keyed markers are the
+    /// only way for several crates to share a target file without clobbering each other.
+    ///
+    /// Man pages get roff comments, e.g. `.\" CONFIG_DOCS_START`, instead of markdown ones.
+    fn new(args: &ConfigDocsArgs, struct_name: &str) -> Self {
+        let marker = args.marker.as_deref().unwrap_or("CONFIG_DOCS");
+        let section = if args.isolate_section {
+            let crate_name = args
+                .crate_name
+                .clone()
+                .or_else(|| std::env::var("CARGO_CRATE_NAME").ok())
+                .unwrap_or_else(|| "unknown".to_string());
+            format!(" {crate_name}::{struct_name}")
+        } else {
+            String::new()
+        };
+
+        let (comment, separator): (fn(&str) -> String, _) = match args.format {
+            OutputFormat::Roff => (|text| format!(".\\\" {text}"), "\n"),
+            _ => (|text| format!("[//]: # ({text})"), "\n\n"),
+        };
+        Markers {
+            start: comment(&format!("{marker}_START{section}")),
+            end: comment(&format!("{marker}_END{section}")),
+            separator,
+        }
     }
+}
 
-    Ok(result)
+/// Find a marker on a line of its own at or after `from`, outside of fenced code blocks
+///
+/// Other marker pairs in the file are left alone, as are markers quoted in examples.
+fn find_marker(content: &str, marker: &str, from: usize) -> Option<usize> {
+    let mut in_fence = false;
+    let mut offset = 0;
+    for line in content.split_inclusive('\n') {
+        let trimmed = line.trim();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        } else if !in_fence && offset >= from && trimmed == marker {
+            return Some(offset + line.find(marker)?);
+        }
+        offset += line.len();
+    }
+    None
 }
 
-/// Apply field name transformation based on clap rename_all setting
-fn apply_field_name_transformation(field_name: &str, rename_all: &Option<CaseStyle>) -> String {
-    match rename_all {
-        Some(CaseStyle::Snake) => field_name.to_snake_case(),
-        Some(CaseStyle::Camel) => field_name.to_lower_camel_case(),
-        Some(CaseStyle::Pascal) => field_name.to_pascal_case(),
-        Some(CaseStyle::Kebab) => field_name.to_kebab_case(),
-        Some(CaseStyle::ScreamingKebab) => field_name.to_shouty_kebab_case(),
-        Some(CaseStyle::ScreamingSnake) => field_name.to_shouty_snake_case(),
-        None => field_name.to_owned(),
+/// Resolve a relative target path according to `path_base`
+fn resolve_target_path(target_path: &str, args: &ConfigDocsArgs) -> PathBuf {
+    let path = StdPath::new(target_path);
+    match (&args.path_base, std::env::var_os("CARGO_MANIFEST_DIR")) {
+        (PathBase::Manifest, Some(manifest_dir)) if path.is_relative() => {
+            PathBuf::from(manifest_dir).join(path)
+        }
+        _ => path.to_path_buf(),
     }
 }
 
-/// Expand nested structs for flattened fields
-fn expand_nested_structs(struct_info: StructInfo) -> syn::Result<StructInfo> {
-    let mut expanded_fields = Vec::new();
+/// Age after which a target's lock is taken over, as left behind by a killed build
+const TARGET_LOCK_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
 
-    for field in struct_info.fields {
-        if field.clap_attrs.flatten {
-            if let Some(nested_struct) = get_registered_struct(&field.field_type) {
-                for nested_field in nested_struct.fields {
-                    let mut expanded_field = nested_field.clone();
-                    expanded_field.group = field.field_type.clone();
-                    expanded_field.name = apply_field_name_transformation(
-                        &expanded_field.name,
-                        &struct_info.clap_rename_all,
-                    );
-
-                    expanded_fields.push(expanded_field);
+/// A lock on a generated file, shared by the rustc processes cargo runs in parallel
+///
+/// Held as a `.<file>.lock` file next to the target, created exclusively and removed on drop.
+/// A lock file last modified more than [`TARGET_LOCK_TIMEOUT`] ago is assumed to be left
+/// behind by a killed build.
+struct TargetLock {
+    path: PathBuf,
+}
+
+impl TargetLock {
+    fn acquire(target: &StdPath) -> std::io::Result<Self> {
+        let path = sibling_path(target, "lock");
+        loop {
+            match fs::OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(&path)
+            {
+                Ok(_) => return Ok(TargetLock { path }),
+                Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => {
+                    let age = fs::metadata(&path)
+                        .and_then(|metadata| metadata.modified())
+                        .ok()
+                        .and_then(|modified| modified.elapsed().ok());
+                    match age {
+                        Some(age) if age >= TARGET_LOCK_TIMEOUT => {
+                            fs::remove_file(&path).ok();
+                        }
+                        // Gone in between, or held by a running build
+                        _ => std::thread::sleep(std::time::Duration::from_millis(10)),
+                    }
                 }
-            } else {
-                let note = format!(
-                    "Note: This field is flattened from {} (not registered)",
-                    field.field_type
-                );
-                let mut expanded_field = field.clone();
-                expanded_field.doc_comment = Some(note);
-                expanded_fields.push(expanded_field);
+                Err(e) => return Err(e),
             }
-        } else {
-            expanded_fields.push(field);
         }
     }
+}
 
-    Ok(StructInfo {
-        name: struct_info.name,
-        fields: expanded_fields,
-        clap_rename_all: struct_info.clap_rename_all,
+/// Lock a target for writing, or nothing in check mode, which only reads and may run on a
+/// read-only checkout
+fn lock_target(
+    file_path: &StdPath,
+    target: &str,
+    args: &ConfigDocsArgs,
+) -> syn::Result<Option<TargetLock>> {
+    if is_check(args) {
+        return Ok(None);
+    }
+
+    TargetLock::acquire(file_path).map(Some).map_err(|e| {
+        syn::Error::new(
+            Span::call_site(),
+            format!("Failed to lock file {target}: {e}"),
+        )
     })
 }
 
-/// Get a registered struct from the global registry
-fn get_registered_struct(struct_name: &str) -> Option<StructInfo> {
-    let registry = STRUCT_REGISTRY.read().unwrap();
-    registry.get(struct_name).cloned()
+impl Drop for TargetLock {
+    fn drop(&mut self) {
+        fs::remove_file(&self.path).ok();
+    }
+}
+
+/// A hidden file next to `target`, e.g. `docs/.config.md.lock`
+fn sibling_path(target: &StdPath, extension: &str) -> PathBuf {
+    let file_name = target
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    target.with_file_name(format!(".{file_name}.{extension}"))
+}
+
+/// Replace a file in one step, so a concurrent reader never sees it half-written
+///
+/// The content goes to a temporary file in the same directory, renamed over the target.
+fn write_atomically(path: &StdPath, content: &str) -> std::io::Result<()> {
+    let temp_path = sibling_path(path, &format!("{}.tmp", std::process::id()));
+    fs::write(&temp_path, content)
+        .and_then(|()| fs::rename(&temp_path, path))
+        .inspect_err(|_| {
+            fs::remove_file(&temp_path).ok();
+        })
 }
 
 /// Update the target file with the generated markdown table
-fn update_target_file(target_path: &str, markdown_table: &str) -> syn::Result<()> {
-    let start_marker = "[//]: # (CONFIG_DOCS_START)";
-    let end_marker = "[//]: # (CONFIG_DOCS_END)";
+///
+/// With `write_if_absent_only`, a managed region that already has content is left untouched.
+/// The file is locked from reading it to writing it, so tables generated into the same file
+/// by concurrent builds don't overwrite each other.
+fn update_target_file(
+    target_path: &str,
+    markdown_table: &str,
+    markers: &Markers,
+    args: &ConfigDocsArgs,
+) -> syn::Result<()> {
+    let start_marker = markers.start.as_str();
+    let end_marker = markers.end.as_str();
+    let separator = markers.separator;
 
-    let content = if StdPath::new(target_path).exists() {
-        fs::read_to_string(target_path).map_err(|e| {
+    let file_path = resolve_target_path(target_path, args);
+    let _lock = lock_target(&file_path, target_path, args)?;
+    let exists = file_path.exists();
+    let content = if exists {
+        fs::read_to_string(&file_path).map_err(|e| {
             syn::Error::new(
                 Span::call_site(),
                 format!("Failed to read file {target_path}: {e}"),
             )
         })?
     } else {
-        format!("{start_marker}\n\n{end_marker}")
+        format!("{start_marker}{separator}{end_marker}")
     };
 
     // Find the markers and replace content between them
-    let updated_content = if let (Some(start_pos), Some(end_pos)) =
-        (content.find(start_marker), content.find(end_marker))
-    {
+    let start_pos = find_marker(&content, start_marker, 0);
+    let end_pos = start_pos.and_then(|start_pos| find_marker(&content, end_marker, start_pos));
+    let managed = start_pos.zip(end_pos).map(|(start, end)| (start, content.len() - end));
+    let updated_content = if let (Some(start_pos), Some(end_pos)) = (start_pos, end_pos) {
         let before = &content[..start_pos + start_marker.len()];
         let after = &content[end_pos..];
+        let existing = content.get(before.len()..end_pos).unwrap_or_default();
+        if args.write_if_absent_only && !existing.trim().is_empty() {
+            return Ok(());
+        }
+        // Check mode never writes, so there is no content to lose
+        if !is_check(args) {
+            check_shrink(target_path, existing, markdown_table, args)?;
+        }
         // Ensure there's at least one empty line before and after the table content
-        format!("{before}\n\n{markdown_table}\n\n{after}")
+        format!("{before}{separator}{markdown_table}{separator}{after}")
     } else {
         // If markers don't exist, append them with the table
-        format!("{content}\n{start_marker}\n\n{markdown_table}\n\n{end_marker}",)
+        format!("{content}\n{start_marker}{separator}{markdown_table}{separator}{end_marker}",)
     };
 
-    fs::write(target_path, updated_content).map_err(|e| {
+    if is_check(args) {
+        return check_up_to_date(
+            target_path,
+            exists.then_some(content.as_str()),
+            &updated_content,
+            managed,
+            args,
+        );
+    }
+
+    print_to_stdout(target_path, markdown_table);
+    write_atomically(&file_path, &updated_content).map_err(|e| {
         syn::Error::new(
             Span::call_site(),
             format!("Failed to write file {target_path}: {e}"),
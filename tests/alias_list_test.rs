@@ -0,0 +1,57 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+const NAME_ALIASES: [&str; 1] = ["database"];
+const NAME_SHORT_ALIAS: char = 'n';
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/alias_list_output.md")]
+pub struct AliasListConfig {
+    /// Database URL
+    #[clap(
+        long,
+        visible_aliases = ["database-url", "db"],
+        aliases = ["dsn"],
+        visible_short_aliases = ['u'],
+        short_aliases = ['D', 'x']
+    )]
+    pub db_url: String,
+
+    /// Database name
+    #[clap(long, visible_aliases = NAME_ALIASES, short_alias = NAME_SHORT_ALIAS)]
+    pub db_name: Option<String>,
+}
+
+#[test]
+fn test_alias_lists() {
+    assert!(std::path::Path::new("tests/output/alias_list_output.md").exists());
+
+    let content = std::fs::read_to_string("tests/output/alias_list_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name                                                          | Type   | Required | Default | Env | Details       | Group           |",
+        "|---------------------------------------------------------------------|--------|----------|---------|-----|---------------|-----------------|",
+        "| db-url (aliases: database-url, db, -u; hidden aliases: dsn, -D, -x) | String | Yes      | -       | -   | Database URL  | AliasListConfig |",
+        "| db-name                                                             | String | No       | -       | -   | Database name | AliasListConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_alias_lists_parse_with_clap() {
+    for args in [
+        ["app", "--db", "a"],
+        ["app", "--dsn", "a"],
+        ["app", "-x", "a"],
+    ] {
+        let config = AliasListConfig::try_parse_from(args).unwrap();
+        assert_eq!(config.db_url, "a");
+    }
+}
@@ -0,0 +1,36 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/alias_output.md")]
+pub struct AliasConfig {
+    /// Database host
+    #[clap(env = "PG_HOST", long, alias = "PgHost")]
+    pub pg_host: String,
+
+    /// Database port
+    #[clap(env = "PG_PORT", long, visible_alias = "pg_port", default_value_t = 5432)]
+    pub pg_port: u16,
+}
+
+#[test]
+fn test_explicit_aliases_are_verbatim() {
+    assert!(std::path::Path::new("tests/output/alias_output.md").exists());
+
+    let content = std::fs::read_to_string("tests/output/alias_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name                       | Type   | Required | Default | Env     | Details                                                             | Group       |",
+        "|----------------------------------|--------|----------|---------|---------|---------------------------------------------------------------------|-------------|",
+        "| pg-host (hidden aliases: PgHost) | String | Yes      | -       | PG_HOST | Database host                                                       | AliasConfig |",
+        "| pg-port (aliases: pg_port)       | u16    | No       | 5432    | PG_PORT | Database port (default if neither `--pg-port` nor `PG_PORT` is set) | AliasConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,40 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(allow_missing_positional = true)]
+#[generate(target = "tests/output/allow_missing_positional_output.md")]
+pub struct CopyConfig {
+    /// Source file
+    #[clap(default_value = "-")]
+    pub source: String,
+
+    /// Destination file
+    pub destination: String,
+}
+
+#[test]
+fn test_allow_missing_positional_note() {
+    let config = CopyConfig::parse_from(["test", "out.txt"]);
+    assert_eq!(config.source, "-");
+    assert_eq!(config.destination, "out.txt");
+
+    let content =
+        std::fs::read_to_string("tests/output/allow_missing_positional_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name  | Type   | Required | Default | Env | Details          | Group      |",
+        "|-------------|--------|----------|---------|-----|------------------|------------|",
+        "| source      | String | No       | -       | -   | Source file      | CopyConfig |",
+        "| destination | String | Yes      | -       | -   | Destination file | CopyConfig |",
+        "",
+        "Note: an optional positional argument may be omitted even when a later positional argument is given.",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,37 @@
+use clap::{ArgAction, Parser};
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/append_action_output.md")]
+pub struct CompilerConfig {
+    /// Include directories
+    #[clap(short = 'I', long, action = ArgAction::Append)]
+    pub include: Vec<String>,
+
+    /// Source files
+    #[clap(long)]
+    pub sources: Vec<String>,
+}
+
+#[test]
+fn test_append_action_note() {
+    let config = CompilerConfig::parse_from(["test", "-I", "src", "--include", "vendor"]);
+    assert_eq!(config.include, ["src", "vendor"]);
+
+    let content = std::fs::read_to_string("tests/output/append_action_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type              | Required | Default | Env | Details                                   | Group          |",
+        "|------------|-------------------|----------|---------|-----|-------------------------------------------|----------------|",
+        "| include    | String (multiple) | No       | -       | -   | Include directories (repeatable, appends) | CompilerConfig |",
+        "| sources    | String (multiple) | No       | -       | -   | Source files                              | CompilerConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,68 @@
+mod derive_clap {
+    use clap::{Args, Parser};
+    use clap_autodoc::{generate, register};
+
+    #[derive(Clone, Debug, Args)]
+    #[clap(rename_all = "kebab-case")]
+    #[register]
+    pub struct TlsConfig {
+        /// Certificate path
+        #[clap(long, env = "TLS_CERT")]
+        pub tls_cert: Option<String>,
+    }
+
+    #[derive(Clone, Debug, Parser)]
+    #[clap(rename_all = "kebab-case")]
+    #[generate(target = "tests/output/arg_command_clap_output.md")]
+    pub struct Config {
+        /// Server host
+        #[clap(long, env = "HOST", default_value = "localhost")]
+        pub server_host: String,
+
+        /// TLS configuration
+        #[clap(flatten)]
+        pub tls: TlsConfig,
+    }
+}
+
+mod derive_arg {
+    use clap::{Args, Parser};
+    use clap_autodoc::{generate, register};
+
+    #[derive(Clone, Debug, Args)]
+    #[command(rename_all = "kebab-case")]
+    #[register]
+    pub struct TlsConfig {
+        /// Certificate path
+        #[arg(long, env = "TLS_CERT")]
+        pub tls_cert: Option<String>,
+    }
+
+    #[derive(Clone, Debug, Parser)]
+    #[command(rename_all = "kebab-case")]
+    #[generate(target = "tests/output/arg_command_output.md")]
+    pub struct Config {
+        /// Server host
+        #[arg(long, env = "HOST")]
+        #[arg(default_value = "localhost")]
+        pub server_host: String,
+
+        /// TLS configuration
+        #[command(flatten)]
+        pub tls: TlsConfig,
+    }
+}
+
+#[test]
+fn test_arg_and_command_attributes() {
+    use clap::Parser;
+
+    let config = derive_arg::Config::parse_from(["test", "--server-host", "example.com"]);
+    assert_eq!(config.server_host, "example.com");
+
+    let clap_content = std::fs::read_to_string("tests/output/arg_command_clap_output.md").unwrap();
+    let content = std::fs::read_to_string("tests/output/arg_command_output.md").unwrap();
+    assert_eq!(content, clap_content);
+    assert!(content.contains("| server-host | String"));
+    assert!(content.contains("| TLS_CERT |"));
+}
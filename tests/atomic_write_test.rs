@@ -0,0 +1,36 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/atomic_write_output.md")]
+pub struct AtomicConfig {
+    /// Listen port
+    #[clap(long, default_value_t = 8080)]
+    pub port: u16,
+}
+
+#[test]
+fn test_writes_leave_no_lock_or_temporary_files() {
+    let content = std::fs::read_to_string("tests/output/atomic_write_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type | Required | Default | Env | Details     | Group        |",
+        "|------------|------|----------|---------|-----|-------------|--------------|",
+        "| port       | u16  | No       | 8080    | -   | Listen port | AtomicConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+
+    let leftovers: Vec<String> = std::fs::read_dir("tests/output")
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .filter(|name| name.starts_with(".atomic_write_output.md."))
+        .collect();
+    assert!(leftovers.is_empty(), "left behind: {leftovers:?}");
+}
@@ -36,19 +36,19 @@ fn test_basic_functionality() {
     let content = std::fs::read_to_string("tests/output/test_output.md").unwrap();
 
     // Expected output for flat table format (using tabled's formatting)
-    let expected = vec![
+    let expected = [
         "[//]: # (CONFIG_DOCS_START)",
         "",
-        "| Field Name               | Type   | Required | Default        | Details       | Group      |",
-        "|--------------------------|--------|----------|----------------|---------------|------------|",
-        "| postgres-host            | String | Yes      | -              | Database host | TestConfig |",
-        "| postgres-port            | u16    | No       | 5432           | Database port | TestConfig |",
-        "| postgres-user            | String | Yes      | -              |               | TestConfig |",
-        "| postgres-password        | String | Yes      | -              |               | TestConfig |",
-        "| postgres-database        | String | No       | data-ingestion |               | TestConfig |",
-        "| postgres-connection-pool | u32    | No       | 5              |               | TestConfig |",
+        "| Field Name               | Type   | Required | Default        | Env                      | Details                                                                                 | Group      |",
+        "|--------------------------|--------|----------|----------------|--------------------------|-----------------------------------------------------------------------------------------|------------|",
+        "| postgres-host            | String | Yes      | -              | POSTGRES_HOST            | Database host                                                                           | TestConfig |",
+        "| postgres-port            | u16    | No       | 5432           | POSTGRES_PORT            | Database port (default if neither `--postgres-port` nor `POSTGRES_PORT` is set)         | TestConfig |",
+        "| postgres-user            | String | Yes      | -              | POSTGRES_USER            |                                                                                         | TestConfig |",
+        "| postgres-password        | String | Yes      | -              | POSTGRES_PASSWORD        |                                                                                         | TestConfig |",
+        "| postgres-database        | String | No       | data-ingestion | POSTGRES_DATABASE        | (default if neither `--postgres-database` nor `POSTGRES_DATABASE` is set)               | TestConfig |",
+        "| postgres-connection-pool | u32    | No       | 5              | POSTGRES_CONNECTION_POOL | (default if neither `--postgres-connection-pool` nor `POSTGRES_CONNECTION_POOL` is set) | TestConfig |",
         "",
-        "[//]: # (CONFIG_DOCS_END)"
+        "[//]: # (CONFIG_DOCS_END)",
     ].join("\n");
 
     // Compare the generated content with expected output
@@ -0,0 +1,37 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/boolish_output.md", type_style = "clap")]
+pub struct FeatureConfig {
+    /// Enable caching
+    #[clap(long, env = "CACHE", value_parser = clap::builder::BoolishValueParser::new())]
+    pub cache: bool,
+
+    /// Cache size in megabytes
+    #[clap(long, default_value_t = 64)]
+    pub cache_size: u32,
+}
+
+#[test]
+fn test_boolish_value_parser_renders_as_flag() {
+    let config = FeatureConfig::parse_from(["test", "--cache"]);
+    assert!(config.cache);
+
+    let content = std::fs::read_to_string("tests/output/boolish_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type             | Required | Default | Env   | Details                                                          | Group         |",
+        "|------------|------------------|----------|---------|-------|------------------------------------------------------------------|---------------|",
+        "| cache      | flag             | No       | false   | CACHE | Enable caching (default if neither `--cache` nor `CACHE` is set) | FeatureConfig |",
+        "| cache-size | `[<CACHE_SIZE>]` | No       | 64      | -     | Cache size in megabytes                                          | FeatureConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,41 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/cfg_default_output.md")]
+pub struct PlatformConfig {
+    /// Number of worker threads
+    #[clap(long, default_value_t = if cfg!(windows) { 2 } else { 4 })]
+    pub workers: u32,
+
+    /// Shell used to run hooks
+    #[clap(
+        long,
+        default_value_t = String::from(if cfg!(windows) { "cmd" } else { "sh" })
+    )]
+    #[clap_autodoc(default_display = "`sh`, or `cmd` on Windows")]
+    pub shell: String,
+}
+
+#[test]
+fn test_cfg_default_placeholder() {
+    let config = PlatformConfig::parse_from(["test"]);
+    assert!(config.workers > 0);
+
+    let content = std::fs::read_to_string("tests/output/cfg_default_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default                   | Env | Details                  | Group          |",
+        "|------------|--------|----------|---------------------------|-----|--------------------------|----------------|",
+        "| workers    | u32    | No       | (platform-dependent)      | -   | Number of worker threads | PlatformConfig |",
+        "| shell      | String | No       | `sh`, or `cmd` on Windows | -   | Shell used to run hooks  | PlatformConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
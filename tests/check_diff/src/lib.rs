@@ -0,0 +1,17 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+// stale_docs.md is committed without the port and with an older host description, as if it
+// was not regenerated. The wider Details column changes the header, right below the marker.
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "stale_docs.md", check = true, check_diff = true)]
+pub struct CheckDiffConfig {
+    /// Database host name
+    #[clap(long)]
+    pub database_host: String,
+
+    /// Database port
+    #[clap(long, default_value_t = 5432)]
+    pub database_port: u16,
+}
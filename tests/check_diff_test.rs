@@ -0,0 +1,38 @@
+mod common;
+
+use common::Fixture;
+
+#[test]
+fn test_check_diff_prints_stale_lines() {
+    let output = Fixture::lock("check_diff").check(&[]);
+    assert!(!output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let diff = stderr
+        .lines()
+        .skip_while(|line| !line.starts_with("--- "))
+        .take_while(|line| !line.starts_with("error"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    // Context stops at the markers, the surrounding text is never shown
+    let expected = [
+        "--- stale_docs.md (current)",
+        "+++ stale_docs.md (generated)",
+        "@@ -3,7 +3,8 @@",
+        " [//]: # (CONFIG_DOCS_START)",
+        " ",
+        "-| Field Name    | Type   | Required | Default | Env | Details       | Group           |",
+        "-|---------------|--------|----------|---------|-----|---------------|-----------------|",
+        "-| database-host | String | Yes      | -       | -   | Database host | CheckDiffConfig |",
+        "+| Field Name    | Type   | Required | Default | Env | Details            | Group           |",
+        "+|---------------|--------|----------|---------|-----|--------------------|-----------------|",
+        "+| database-host | String | Yes      | -       | -   | Database host name | CheckDiffConfig |",
+        "+| database-port | u16    | No       | 5432    | -   | Database port      | CheckDiffConfig |",
+        " ",
+        " [//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(diff.trim(), expected.trim());
+}
@@ -0,0 +1,44 @@
+use clap::Parser;
+use clap_autodoc::{generate, written_paths};
+
+// tests/output/check_output.md is committed and never written by the build
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/check_output.md", check = true)]
+pub struct CheckConfig {
+    /// Database host
+    #[clap(long, env = "DATABASE_HOST")]
+    pub database_host: String,
+}
+
+#[test]
+fn test_check_passes_for_up_to_date_docs() {
+    let content = std::fs::read_to_string("tests/output/check_output.md").unwrap();
+
+    let expected = [
+        "# Check",
+        "",
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name    | Type   | Required | Default | Env           | Details       | Group       |",
+        "|---------------|--------|----------|---------|---------------|---------------|-------------|",
+        "| database-host | String | Yes      | -       | DATABASE_HOST | Database host | CheckConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_check_mode_records_up_to_date_paths() {
+    let paths: &[&str] = written_paths!(CheckConfig);
+    assert_eq!(
+        paths,
+        [concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/output/check_output.md"
+        )]
+    );
+}
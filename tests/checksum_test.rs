@@ -0,0 +1,85 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/checksum_output.md",
+    emit_checksum = "tests/output/checksum_output.md.fnv"
+)]
+pub struct ChecksumConfig {
+    /// Listen port
+    #[clap(long, default_value_t = 8080)]
+    pub port: u16,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/checksum_dotenv_output.env",
+    format = "dotenv",
+    emit_checksum = "tests/output/checksum_dotenv_output.env.fnv"
+)]
+pub struct ChecksumDotenvConfig {
+    /// Listen port
+    #[clap(long, env = "PORT", default_value_t = 8080)]
+    pub port: u16,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    split_by_group = true,
+    split_target_template = "tests/output/checksum_split/{group}.md",
+    emit_checksum = "tests/output/checksum_split/{group}.md.fnv"
+)]
+pub struct ChecksumSplitConfig {
+    /// Listen port
+    #[clap(long, default_value_t = 8080, help_heading = "Server")]
+    pub port: u16,
+
+    /// Database host
+    #[clap(long, help_heading = "Database")]
+    pub database_host: String,
+}
+
+/// 64-bit FNV-1a, as documented for `emit_checksum`
+fn fnv1a_64(text: &str) -> u64 {
+    text.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Assert that the checksum file next to a target holds the hash of its managed region
+fn assert_region_checksum(target: &str) {
+    let content = std::fs::read_to_string(target).unwrap();
+    let checksum = std::fs::read_to_string(format!("{target}.fnv")).unwrap();
+
+    let start = "[//]: # (CONFIG_DOCS_START)";
+    let end = "[//]: # (CONFIG_DOCS_END)";
+    let region = &content[content.find(start).unwrap() + start.len()..content.find(end).unwrap()];
+
+    assert_eq!(checksum, format!("{:016x}\n", fnv1a_64(region.trim())));
+}
+
+#[test]
+fn test_checksum_matches_managed_region() {
+    // Reference vector of FNV-1a 64
+    assert_eq!(fnv1a_64("a"), 0xaf63dc4c8601ec8c);
+
+    assert_region_checksum("tests/output/checksum_output.md");
+}
+
+#[test]
+fn test_checksum_per_split_file() {
+    assert_region_checksum("tests/output/checksum_split/server.md");
+    assert_region_checksum("tests/output/checksum_split/database.md");
+}
+
+#[test]
+fn test_checksum_covers_whole_dotenv_file() {
+    let content = std::fs::read_to_string("tests/output/checksum_dotenv_output.env").unwrap();
+    let checksum = std::fs::read_to_string("tests/output/checksum_dotenv_output.env.fnv").unwrap();
+
+    assert_eq!(checksum, format!("{:016x}\n", fnv1a_64(content.trim())));
+}
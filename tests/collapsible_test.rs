@@ -0,0 +1,78 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/collapsible_output.md",
+    collapsible = true,
+    title = "Server options"
+)]
+pub struct CollapsibleConfig {
+    /// Server host
+    #[clap(env = "SERVER_HOST", long)]
+    pub host: String,
+}
+
+/// Options of the admin server
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/collapsible_intro_output.md",
+    collapsible = true
+)]
+pub struct CollapsibleIntroConfig {
+    /// Admin port
+    #[clap(long, default_value_t = 9090)]
+    pub admin_port: u16,
+}
+
+#[test]
+fn test_collapsible_details_wrapper() {
+    assert!(std::path::Path::new("tests/output/collapsible_output.md").exists());
+
+    let content = std::fs::read_to_string("tests/output/collapsible_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "<details>",
+        "<summary>Server options</summary>",
+        "",
+        "| Field Name | Type   | Required | Default | Env         | Details     | Group             |",
+        "|------------|--------|----------|---------|-------------|-------------|-------------------|",
+        "| host       | String | Yes      | -       | SERVER_HOST | Server host | CollapsibleConfig |",
+        "",
+        "</details>",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_collapsible_keeps_struct_intro() {
+    let content = std::fs::read_to_string("tests/output/collapsible_intro_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "<details>",
+        "<summary>Configuration</summary>",
+        "",
+        "Options of the admin server",
+        "",
+        "| Field Name | Type | Required | Default | Env | Details    | Group                  |",
+        "|------------|------|----------|---------|-----|------------|------------------------|",
+        "| admin-port | u16  | No       | 9090    | -   | Admin port | CollapsibleIntroConfig |",
+        "",
+        "</details>",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,37 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/columns_output.md",
+    columns = "name, default, type, details"
+)]
+pub struct ColumnsConfig {
+    /// Server host
+    #[clap(long, env = "SERVER_HOST")]
+    pub host: String,
+
+    /// Server port
+    #[clap(long, default_value_t = 8080)]
+    pub port: u16,
+}
+
+#[test]
+fn test_columns() {
+    let content = std::fs::read_to_string("tests/output/columns_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Default | Type   | Details     |",
+        "|------------|---------|--------|-------------|",
+        "| host       | -       | String | Server host |",
+        "| port       | 8080    | u16    | Server port |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,130 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[command(name = "mytool", rename_all = "kebab-case")]
+#[generate(target = "tests/output/command_name_output.md", collapsible = true)]
+pub struct MyToolConfig {
+    /// Server host
+    #[clap(long)]
+    pub host: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[command(name = "mytool", rename_all = "kebab-case")]
+#[generate(target = "tests/output/command_name_flat_output.md")]
+pub struct MyToolFlatConfig {
+    /// Server host
+    #[clap(long)]
+    pub host: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[command(name = "mytool", rename_all = "kebab-case")]
+#[generate(target = "tests/output/command_name_grouped_output.md", format = "grouped")]
+pub struct MyToolGroupedConfig {
+    /// Server host
+    #[clap(long)]
+    pub host: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[command(name = "mytool", rename_all = "kebab-case")]
+#[generate(target = "tests/output/command_name_sections_output.md", format = "sections")]
+pub struct MyToolSectionsConfig {
+    /// Server host
+    #[clap(long)]
+    pub host: String,
+}
+
+#[test]
+fn test_command_name_in_title() {
+    let content = std::fs::read_to_string("tests/output/command_name_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "<details>",
+        "<summary>mytool Configuration</summary>",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details     | Group        |",
+        "|------------|--------|----------|---------|-----|-------------|--------------|",
+        "| host       | String | Yes      | -       | -   | Server host | MyToolConfig |",
+        "",
+        "</details>",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_command_name_in_flat_heading() {
+    let content = std::fs::read_to_string("tests/output/command_name_flat_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "# mytool Configuration",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details     | Group            |",
+        "|------------|--------|----------|---------|-----|-------------|------------------|",
+        "| host       | String | Yes      | -       | -   | Server host | MyToolFlatConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_command_name_in_grouped_heading() {
+    let content = std::fs::read_to_string("tests/output/command_name_grouped_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "# mytool Configuration",
+        "",
+        "## MyToolGroupedConfig Configuration",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details     |",
+        "|------------|--------|----------|---------|-----|-------------|",
+        "| host       | String | Yes      | -       | -   | Server host |",
+        "",
+        "",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_command_name_in_sections_heading() {
+    let content = std::fs::read_to_string("tests/output/command_name_sections_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "# mytool Configuration",
+        "",
+        "### --host",
+        "",
+        "Server host",
+        "",
+        "- Type: String",
+        "- Default: -",
+        "- Env: -",
+        "- Required: Yes",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,53 @@
+//! Building the fixture crates under `tests/`, whose proc macro output is only observable from
+//! a separate `cargo check`
+
+use std::process::{Command, Output};
+use std::sync::{Mutex, MutexGuard};
+
+/// Held while a fixture is built, tests reusing a fixture would race on its files otherwise
+static FIXTURE_LOCK: Mutex<()> = Mutex::new(());
+
+/// A fixture crate, locked until dropped
+///
+/// Tests building a fixture more than once, e.g. to compare two runs, keep it locked between
+/// the builds.
+pub struct Fixture {
+    name: &'static str,
+    _guard: MutexGuard<'static, ()>,
+}
+
+impl Fixture {
+    /// Lock the fixture crate in `tests/<name>`
+    pub fn lock(name: &'static str) -> Self {
+        // A failed test poisons the lock, which leaves the fixture usable
+        let guard = FIXTURE_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        Fixture {
+            name,
+            _guard: guard,
+        }
+    }
+
+    /// Run `cargo check` on the fixture with the given environment variables
+    ///
+    /// The fixture's source is touched first, proc macros only run when their crate is rebuilt.
+    pub fn check(&self, envs: &[(&str, &str)]) -> Output {
+        let manifest_dir = env!("CARGO_MANIFEST_DIR");
+        let source = format!("{manifest_dir}/tests/{}/src/lib.rs", self.name);
+        std::fs::File::options()
+            .write(true)
+            .open(&source)
+            .and_then(|file| file.set_modified(std::time::SystemTime::now()))
+            .unwrap();
+
+        Command::new(env!("CARGO"))
+            .args(["check", "--quiet", "--offline", "--manifest-path"])
+            .arg(format!("{manifest_dir}/tests/{}/Cargo.toml", self.name))
+            .arg("--target-dir")
+            .arg(format!("{manifest_dir}/target/tests/fixtures"))
+            .envs(envs.iter().copied())
+            .output()
+            .unwrap()
+    }
+}
@@ -0,0 +1,52 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+const TOKEN_ID: &str = "access_token";
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/constraints_output.md")]
+pub struct UploadConfig {
+    /// Upload to the public bucket
+    #[clap(long, conflicts_with = "private_bucket", requires = "access_token")]
+    pub public_bucket: bool,
+
+    /// Upload to the private bucket
+    #[clap(long, conflicts_with_all = ["public_bucket", "dry_run"])]
+    pub private_bucket: bool,
+
+    /// Access token
+    #[clap(long = "token")]
+    pub access_token: Option<String>,
+
+    /// Only print what would be uploaded
+    #[clap(long, id = "dry_run", requires = TOKEN_ID)]
+    pub simulate: bool,
+}
+
+#[test]
+fn test_conflicts_and_requires() {
+    assert!(UploadConfig::try_parse_from(["test", "--public-bucket"]).is_err());
+    assert!(UploadConfig::try_parse_from(["test", "--private-bucket", "--simulate"]).is_err());
+    let config = UploadConfig::parse_from(["test", "--public-bucket", "--token", "t"]);
+    assert!(config.public_bucket);
+    assert!(UploadConfig::try_parse_from(["test", "--simulate"]).is_err());
+
+    let content = std::fs::read_to_string("tests/output/constraints_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name     | Type   | Required | Default | Env | Details                                                                                                    | Group        |",
+        "|----------------|--------|----------|---------|-----|------------------------------------------------------------------------------------------------------------|--------------|",
+        "| public-bucket  | bool   | No       | false   | -   | Upload to the public bucket (flag, takes no value) (conflicts with `--private-bucket`; requires `--token`) | UploadConfig |",
+        "| private-bucket | bool   | No       | false   | -   | Upload to the private bucket (flag, takes no value) (conflicts with `--public-bucket`, `--simulate`)       | UploadConfig |",
+        "| token          | String | No       | -       | -   | Access token                                                                                               | UploadConfig |",
+        "| simulate       | bool   | No       | false   | -   | Only print what would be uploaded (flag, takes no value)                                                   | UploadConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
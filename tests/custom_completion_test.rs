@@ -0,0 +1,51 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+/// Stand-in for clap_complete's `ArgValueCompleter`, which needs clap's `unstable-ext` feature
+#[derive(Clone, Debug)]
+struct BranchCompleter;
+
+trait CompletionArgExt {
+    fn add(self, completer: BranchCompleter) -> Self;
+}
+
+impl CompletionArgExt for clap::Arg {
+    fn add(self, _completer: BranchCompleter) -> Self {
+        self
+    }
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/custom_completion_output.md")]
+pub struct CheckoutConfig {
+    /// Branch to check out
+    #[clap(long, add = BranchCompleter)]
+    pub branch: String,
+
+    /// Working directory
+    #[clap(long, value_hint(clap::ValueHint::DirPath))]
+    pub workdir: String,
+}
+
+#[test]
+fn test_custom_completion_attributes() {
+    let config = CheckoutConfig::parse_from(["test", "--branch", "main", "--workdir", "."]);
+    assert_eq!(config.branch, "main");
+
+    let content = std::fs::read_to_string("tests/output/custom_completion_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details                                       | Group          |",
+        "|------------|--------|----------|---------|-----|-----------------------------------------------|----------------|",
+        "| branch     | String | Yes      | -       | -   | Branch to check out (custom shell completion) | CheckoutConfig |",
+        "| workdir    | String | Yes      | -       | -   | Working directory                             | CheckoutConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
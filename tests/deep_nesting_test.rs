@@ -0,0 +1,74 @@
+use clap::{Args, Parser};
+use clap_autodoc::{generate, register};
+
+// Registered after the struct flattening it, to cover resolution at every level
+#[derive(Clone, Debug, Args)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct DeepDatabaseConfig {
+    /// Database host
+    #[clap(long, env = "DB_HOST")]
+    pub db_host: String,
+
+    /// Connection pool settings
+    #[clap(flatten)]
+    pub pool: DeepPoolConfig,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/deep_nesting_output.md")]
+pub struct DeepConfig {
+    /// Database configuration
+    #[clap(flatten)]
+    pub database: DeepDatabaseConfig,
+
+    /// Service name
+    #[clap(long)]
+    pub service_name: String,
+}
+
+#[derive(Clone, Debug, Args)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct DeepPoolConfig {
+    /// Maximum pooled connections
+    #[clap(long, default_value_t = 16)]
+    pub pool_size: u32,
+
+    /// Connection timeout in seconds
+    #[clap(long, default_value_t = 30)]
+    pub pool_timeout: u64,
+}
+
+#[test]
+fn test_two_levels_of_flattening() {
+    let config = DeepConfig::parse_from([
+        "test",
+        "--db-host",
+        "localhost",
+        "--pool-size",
+        "4",
+        "--service-name",
+        "api",
+    ]);
+    assert_eq!(config.database.pool.pool_size, 4);
+
+    let content = std::fs::read_to_string("tests/output/deep_nesting_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name   | Type   | Required | Default | Env     | Details                       | Group              |",
+        "|--------------|--------|----------|---------|---------|-------------------------------|--------------------|",
+        "| db-host      | String | Yes      | -       | DB_HOST | Database host                 | DeepDatabaseConfig |",
+        "| pool-size    | u32    | No       | 16      | -       | Maximum pooled connections    | DeepPoolConfig     |",
+        "| pool-timeout | u64    | No       | 30      | -       | Connection timeout in seconds | DeepPoolConfig     |",
+        "| service-name | String | Yes      | -       | -       | Service name                  | DeepConfig         |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
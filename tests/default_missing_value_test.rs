@@ -0,0 +1,84 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+const ALWAYS: &str = "always";
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+// Strict mode fails the build on attributes it does not understand, `require_equals` included
+#[generate(target = "tests/output/default_missing_value_output.md", strict = true)]
+pub struct ColorConfig {
+    /// When to use colors
+    #[clap(
+        long,
+        require_equals = true,
+        num_args = 0..=1,
+        default_value = "auto",
+        default_missing_value = "always"
+    )]
+    pub color: String,
+
+    /// When to page output
+    #[clap(long, num_args = 0..=1, default_missing_value = ALWAYS)]
+    pub pager: Option<String>,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/default_missing_value_clap_output.md",
+    type_style = "clap",
+    strict = true
+)]
+pub struct ClapStyleColorConfig {
+    /// When to use colors
+    #[clap(
+        long,
+        require_equals = true,
+        num_args = 0..=1,
+        default_value = "auto",
+        default_missing_value = "always"
+    )]
+    pub color: String,
+}
+
+#[test]
+fn test_default_missing_value() {
+    assert!(std::path::Path::new("tests/output/default_missing_value_output.md").exists());
+
+    let content =
+        std::fs::read_to_string("tests/output/default_missing_value_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details                                                                                                                                      | Group       |",
+        "|------------|--------|----------|---------|-----|----------------------------------------------------------------------------------------------------------------------------------------------|-------------|",
+        "| color      | String | No       | auto    | -   | When to use colors (value attached with `=`, as in `--color=<COLOR>`) (defaults to `auto` when absent, `always` when passed without a value) | ColorConfig |",
+        "| pager      | String | No       | -       | -   | When to page output                                                                                                                          | ColorConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_require_equals_clap_type_style() {
+    let content =
+        std::fs::read_to_string("tests/output/default_missing_value_clap_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type                | Required | Default | Env | Details                                                                                   | Group                |",
+        "|------------|---------------------|----------|---------|-----|-------------------------------------------------------------------------------------------|----------------------|",
+        "| color      | `--color[=<COLOR>]` | No       | auto    | -   | When to use colors (defaults to `auto` when absent, `always` when passed without a value) | ClapStyleColorConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,111 @@
+use clap::Parser;
+use clap_autodoc::generate;
+use std::path::PathBuf;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/default_values_os_output.md")]
+pub struct SearchConfig {
+    /// Directories searched for plugins
+    #[clap(
+        env = "PLUGIN_DIRS",
+        long,
+        default_values_os_t = vec![PathBuf::from("/usr/lib/app"), PathBuf::from("/etc/app")]
+    )]
+    pub plugin_dirs: Vec<PathBuf>,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/default_values_os_clap_output.md",
+    type_style = "clap"
+)]
+pub struct SearchConfigClapStyle {
+    /// Directories searched for plugins
+    #[clap(
+        env = "PLUGIN_DIRS",
+        long,
+        default_values_os_t = vec![PathBuf::from("/usr/lib/app"), PathBuf::from("/etc/app")]
+    )]
+    pub plugin_dirs: Vec<PathBuf>,
+}
+
+const DEFAULT_PORTS: [u16; 2] = [80, 443];
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/default_values_expr_output.md")]
+pub struct ListenConfig {
+    /// Ports to listen on
+    #[clap(long, default_values_t = DEFAULT_PORTS.to_vec())]
+    pub ports: Vec<u16>,
+
+    /// Ports tried when the others are taken
+    #[clap(long, default_values_t = vec![8080u16, 8081])]
+    pub fallback_ports: Vec<u16>,
+
+    /// Offsets applied to the clock, in seconds
+    #[clap(long, default_values_t = [-1i64, 0])]
+    pub clock_offsets: Vec<i64>,
+}
+
+#[test]
+fn test_default_values_os_t() {
+    assert!(std::path::Path::new("tests/output/default_values_os_output.md").exists());
+
+    let content = std::fs::read_to_string("tests/output/default_values_os_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name  | Type               | Required | Default                | Env         | Details                                                                                        | Group        |",
+        "|-------------|--------------------|----------|------------------------|-------------|------------------------------------------------------------------------------------------------|--------------|",
+        "| plugin-dirs | PathBuf (multiple) | No       | /usr/lib/app, /etc/app | PLUGIN_DIRS | Directories searched for plugins (default if neither `--plugin-dirs` nor `PLUGIN_DIRS` is set) | SearchConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_default_values_os_t_is_optional_and_repeatable() {
+    let content = std::fs::read_to_string("tests/output/default_values_os_clap_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name  | Type                 | Required | Default                | Env         | Details                                                                                        | Group                 |",
+        "|-------------|----------------------|----------|------------------------|-------------|------------------------------------------------------------------------------------------------|-----------------------|",
+        "| plugin-dirs | `[<PLUGIN_DIRS>]...` | No       | /usr/lib/app, /etc/app | PLUGIN_DIRS | Directories searched for plugins (default if neither `--plugin-dirs` nor `PLUGIN_DIRS` is set) | SearchConfigClapStyle |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_computed_default_values_are_spelled() {
+    assert_eq!(ListenConfig::parse_from(["test"]).ports, DEFAULT_PORTS);
+
+    let content = std::fs::read_to_string("tests/output/default_values_expr_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name     | Type           | Required | Default                | Env | Details                                  | Group        |",
+        "|----------------|----------------|----------|------------------------|-----|------------------------------------------|--------------|",
+        "| ports          | u16 (multiple) | No       | DEFAULT_PORTS.to_vec() | -   | Ports to listen on                       | ListenConfig |",
+        "| fallback-ports | u16 (multiple) | No       | 8080, 8081             | -   | Ports tried when the others are taken    | ListenConfig |",
+        "| clock-offsets  | i64 (multiple) | No       | -1, 0                  | -   | Offsets applied to the clock, in seconds | ListenConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,77 @@
+use clap::{Args, Parser};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Args)]
+#[register]
+pub struct DefaultsDatabaseConfig {
+    /// Database host
+    #[clap(long)]
+    pub database_host: String,
+
+    /// Database port
+    #[clap(long, default_value_t = 5432)]
+    pub database_port: u16,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[generate(
+    target = "tests/output/defaults_target_output.md",
+    defaults_target = "tests/output/defaults_target_output.toml"
+)]
+pub struct DefaultsConfig {
+    /// Database configuration
+    #[clap(flatten)]
+    pub database: DefaultsDatabaseConfig,
+
+    /// Name of the service
+    #[clap(long)]
+    pub service_name: String,
+
+    /// Log level
+    #[clap(long, default_value = "info")]
+    pub log_level: String,
+
+    /// Ports to listen on
+    #[clap(long, default_values_t = vec![80, 443])]
+    pub ports: Vec<u16>,
+
+    /// Allowed origins
+    #[clap(long, default_values_t = vec!["localhost".to_string()])]
+    pub origins: Vec<String>,
+}
+
+// Writes the starter config above again, so it has to leave the edited file alone
+#[derive(Clone, Debug, Parser)]
+#[generate(
+    target = "tests/output/defaults_target_kept_output.md",
+    defaults_target = "tests/output/defaults_target_output.toml",
+    write_if_absent_only = true
+)]
+pub struct OverwritingDefaultsConfig {
+    /// Request timeout in seconds
+    #[clap(long, default_value_t = 30)]
+    pub timeout: u64,
+}
+
+#[test]
+fn test_defaults_file_lists_defaulted_fields() {
+    let content = std::fs::read_to_string("tests/output/defaults_target_output.toml").unwrap();
+
+    let expected = [
+        "# Log level",
+        "log-level = \"info\"",
+        "",
+        "# Ports to listen on",
+        "ports = [80, 443]",
+        "",
+        "# Allowed origins",
+        "origins = [\"localhost\"]",
+        "",
+        "[defaults_database_config]",
+        "# Database port",
+        "database-port = 5432",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
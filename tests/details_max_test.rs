@@ -0,0 +1,130 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/details_max_output.md", details_max = 40)]
+pub struct DetailsMaxConfig {
+    /// Database host
+    #[clap(env = "DB_HOST", long)]
+    pub db_host: String,
+
+    /// Maximum number of connections kept open in the pool between requests
+    #[clap(env = "DB_POOL_SIZE", long, default_value_t = 10)]
+    pub db_pool_size: u32,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/details_max_markup_output.md", details_max = 36)]
+pub struct DetailsMaxMarkupConfig {
+    /// Separator between fields, such as a|b or a;b
+    #[clap(long)]
+    pub separator: String,
+
+    /// Pass values on the command line as `--format json`
+    #[clap(long)]
+    pub format: String,
+}
+
+// Two tables in one file, whose footnotes must not share ids
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/details_max_blocks_output.md",
+    marker = "DATABASE_DOCS",
+    details_max = 30
+)]
+pub struct DetailsMaxDatabaseConfig {
+    /// Database host, resolved through the system resolver
+    #[clap(long)]
+    pub database_host: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/details_max_blocks_output.md",
+    marker = "CACHE_DOCS",
+    details_max = 30
+)]
+pub struct DetailsMaxCacheConfig {
+    /// Cache size in megabytes, shared by all workers
+    #[clap(long, default_value_t = 64)]
+    pub cache_size: u32,
+}
+
+#[test]
+fn test_details_truncation_with_footnotes() {
+    assert!(std::path::Path::new("tests/output/details_max_output.md").exists());
+
+    let content = std::fs::read_to_string("tests/output/details_max_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name   | Type   | Required | Default | Env          | Details                                                         | Group            |",
+        "|--------------|--------|----------|---------|--------------|-----------------------------------------------------------------|------------------|",
+        "| db-host      | String | Yes      | -       | DB_HOST      | Database host                                                   | DetailsMaxConfig |",
+        "| db-pool-size | u32    | No       | 10      | DB_POOL_SIZE | Maximum number of connections kept open…[^details-max-config-1] | DetailsMaxConfig |",
+        "",
+        "[^details-max-config-1]: Maximum number of connections kept open in the pool between requests (default if neither `--db-pool-size` nor `DB_POOL_SIZE` is set)",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_details_truncation_keeps_markup_intact() {
+    let content = std::fs::read_to_string("tests/output/details_max_markup_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details                                                            | Group                  |",
+        "|------------|--------|----------|---------|-----|--------------------------------------------------------------------|------------------------|",
+        "| separator  | String | Yes      | -       | -   | Separator between fields, such as a…[^details-max-markup-config-1] | DetailsMaxMarkupConfig |",
+        "| format     | String | Yes      | -       | -   | Pass values on the command line as…[^details-max-markup-config-2]  | DetailsMaxMarkupConfig |",
+        "",
+        "[^details-max-markup-config-1]: Separator between fields, such as a\\|b or a;b",
+        "[^details-max-markup-config-2]: Pass values on the command line as `--format json`",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_footnote_ids_are_unique_per_struct() {
+    let content = std::fs::read_to_string("tests/output/details_max_blocks_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (DATABASE_DOCS_START)",
+        "",
+        "| Field Name    | Type   | Required | Default | Env | Details                                                         | Group                    |",
+        "|---------------|--------|----------|---------|-----|-----------------------------------------------------------------|--------------------------|",
+        "| database-host | String | Yes      | -       | -   | Database host, resolved throug…[^details-max-database-config-1] | DetailsMaxDatabaseConfig |",
+        "",
+        "[^details-max-database-config-1]: Database host, resolved through the system resolver",
+        "",
+        "[//]: # (DATABASE_DOCS_END)",
+        "",
+        "[//]: # (CACHE_DOCS_START)",
+        "",
+        "| Field Name | Type | Required | Default | Env | Details                                                      | Group                 |",
+        "|------------|------|----------|---------|-----|--------------------------------------------------------------|-----------------------|",
+        "| cache-size | u32  | No       | 64      | -   | Cache size in megabytes, share…[^details-max-cache-config-1] | DetailsMaxCacheConfig |",
+        "",
+        "[^details-max-cache-config-1]: Cache size in megabytes, shared by all workers",
+        "",
+        "[//]: # (CACHE_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,48 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/details_source_output.md",
+    details_show_source = true
+)]
+pub struct SourceConfig {
+    /// Database host
+    #[clap(env = "DB_HOST", long)]
+    pub db_host: String,
+
+    /// Not shown, the help attribute wins
+    #[clap(env = "DB_PORT", long, help = "Database port", default_value_t = 5432)]
+    pub db_port: u16,
+
+    #[clap(env = "DB_NAME", long, long_help = "Name of the database")]
+    pub db_name: String,
+
+    /// Database user
+    #[clap(env = "DB_USER", long, long_help = concat!("User ", "to connect as"))]
+    pub db_user: String,
+}
+
+#[test]
+fn test_details_source_markers() {
+    assert!(std::path::Path::new("tests/output/details_source_output.md").exists());
+
+    let content = std::fs::read_to_string("tests/output/details_source_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default | Env     | Details                                                                    | Group        |",
+        "|------------|--------|----------|---------|---------|----------------------------------------------------------------------------|--------------|",
+        "| db-host    | String | Yes      | -       | DB_HOST | Database host [doc]                                                        | SourceConfig |",
+        "| db-port    | u16    | No       | 5432    | DB_PORT | Database port [help] (default if neither `--db-port` nor `DB_PORT` is set) | SourceConfig |",
+        "| db-name    | String | Yes      | -       | DB_NAME | Name of the database [long_help]                                           | SourceConfig |",
+        "| db-user    | String | Yes      | -       | DB_USER | Database user [doc]                                                        | SourceConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
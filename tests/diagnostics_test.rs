@@ -0,0 +1,69 @@
+use clap::{Args, Parser};
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Args)]
+pub struct UnregisteredConfig {
+    /// Never documented
+    #[clap(long)]
+    pub hidden: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/diagnostics_docs_output.md",
+    diagnostics = "tests/output/diagnostics_output.json"
+)]
+pub struct DiagnosticsConfig {
+    /// Database host
+    #[clap(long, env = "DATABASE_HOST")]
+    pub database_host: String,
+
+    #[clap(long, env = "DATABASE_HOST")]
+    pub database_port: u16,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[generate(
+    target = "tests/output/diagnostics_docs_output.md",
+    diagnostics = "tests/output/diagnostics_output.json"
+)]
+pub struct DiagnosticsPendingConfig {
+    #[clap(flatten)]
+    pub unregistered: UnregisteredConfig,
+}
+
+#[test]
+fn test_diagnostics() {
+    assert!(!std::path::Path::new("tests/output/diagnostics_docs_output.md").exists());
+
+    let content = std::fs::read_to_string("tests/output/diagnostics_output.json").unwrap();
+
+    let expected = [
+        "[",
+        "  {",
+        "    \"struct\": \"DiagnosticsConfig\",",
+        "    \"fields\": [",
+        "      {\"name\": \"database-host\", \"type\": \"String\", \"group\": \"DiagnosticsConfig\", \"env\": \"DATABASE_HOST\"},",
+        "      {\"name\": \"database-port\", \"type\": \"u16\", \"group\": \"DiagnosticsConfig\", \"env\": \"DATABASE_HOST\"}",
+        "    ],",
+        "    \"issues\": [",
+        "      {\"kind\": \"undocumented\", \"field\": \"database-port\", \"message\": \"field has no description\"},",
+        "      {\"kind\": \"duplicate_env\", \"field\": \"database-port\", \"message\": \"environment variable DATABASE_HOST is also used by database-host\"}",
+        "    ]",
+        "  },",
+        "  {",
+        "    \"struct\": \"DiagnosticsPendingConfig\",",
+        "    \"fields\": [",
+        "      {\"name\": \"unregistered\", \"type\": \"UnregisteredConfig\", \"group\": \"UnregisteredConfig\", \"env\": null}",
+        "    ],",
+        "    \"issues\": [",
+        "      {\"kind\": \"unresolved_flatten\", \"field\": \"unregistered\", \"message\": \"UnregisteredConfig is not registered with #[register]\"}",
+        "    ]",
+        "  }",
+        "]",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,73 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+macro_rules! documented_config {
+    ($doc:expr) => {
+        #[derive(Clone, Debug, Parser)]
+        #[clap(rename_all = "kebab-case")]
+        #[generate(target = "tests/output/doc_attr_macro_output.md")]
+        pub struct MacroDocConfig {
+            #[doc = $doc]
+            #[clap(long)]
+            pub endpoint: String,
+        }
+    };
+}
+
+documented_config!("Endpoint documented through a macro");
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/doc_attr_output.md")]
+pub struct DocAttrConfig {
+    #[doc = "Server host"]
+    #[clap(long)]
+    pub host: String,
+
+    #[doc = "Server port,"]
+    #[doc = "between 1 and 65535"]
+    #[clap(long, default_value_t = 8080)]
+    pub port: u16,
+
+    #[doc = "Worker threads\nper CPU core"]
+    #[clap(long, default_value_t = 4)]
+    pub workers: u16,
+}
+
+#[test]
+fn test_doc_attributes() {
+    let content = std::fs::read_to_string("tests/output/doc_attr_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details                          | Group         |",
+        "|------------|--------|----------|---------|-----|----------------------------------|---------------|",
+        "| host       | String | Yes      | -       | -   | Server host                      | DocAttrConfig |",
+        "| port       | u16    | No       | 8080    | -   | Server port, between 1 and 65535 | DocAttrConfig |",
+        "| workers    | u16    | No       | 4       | -   | Worker threads per CPU core      | DocAttrConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_doc_attribute_from_macro() {
+    let content = std::fs::read_to_string("tests/output/doc_attr_macro_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details                             | Group          |",
+        "|------------|--------|----------|---------|-----|-------------------------------------|----------------|",
+        "| endpoint   | String | Yes      | -       | -   | Endpoint documented through a macro | MacroDocConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,44 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case", rename_all_env = "SCREAMING_SNAKE_CASE")]
+#[generate(target = "tests/output/dotenv_output.env", format = "dotenv")]
+pub struct DotenvConfig {
+    /// Database URL
+    #[clap(long, env = "DATABASE_URL")]
+    pub database_url: String,
+
+    /// Listen port
+    #[clap(long, env, default_value_t = 8080)]
+    pub listen_port: u16,
+
+    /// Greeting shown on the index page
+    #[clap(long, env, default_value = "Hello there")]
+    pub greeting: String,
+
+    /// Only read from the command line
+    #[clap(long)]
+    pub verbose: bool,
+}
+
+#[test]
+fn test_dotenv_file() {
+    let content = std::fs::read_to_string("tests/output/dotenv_output.env").unwrap();
+
+    let expected = [
+        "# Database URL",
+        "DATABASE_URL=",
+        "",
+        "# Listen port",
+        "LISTEN_PORT=8080",
+        "",
+        "# Greeting shown on the index page",
+        "GREETING=\"Hello there\"",
+        "",
+    ]
+    .join("\n");
+
+    // No markers, the file is loaded by dotenv parsers as is
+    assert_eq!(content, expected);
+}
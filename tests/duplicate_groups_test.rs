@@ -0,0 +1,88 @@
+use clap::Parser;
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct ReplicatedDatabaseConfig {
+    /// Database host
+    #[clap(long)]
+    pub host: String,
+}
+
+// Not a clap parser itself: clap would reject the repeated `--host` argument
+#[derive(Clone, Debug)]
+#[generate(target = "tests/output/duplicate_groups_output.md", format = "grouped")]
+pub struct ReplicatedConfig {
+    /// Primary database
+    #[clap_autodoc(expand, prefix = "primary")]
+    pub primary_db: ReplicatedDatabaseConfig,
+
+    /// Replica database
+    #[clap_autodoc(expand, prefix = "replica")]
+    pub replica_db: ReplicatedDatabaseConfig,
+}
+
+#[derive(Clone, Debug)]
+#[generate(
+    target = "tests/output/merged_groups_output.md",
+    format = "grouped",
+    merge_duplicate_groups = true
+)]
+pub struct MergedConfig {
+    /// Primary database
+    #[clap_autodoc(expand, prefix = "primary")]
+    pub primary_db: ReplicatedDatabaseConfig,
+
+    /// Replica database
+    #[clap_autodoc(expand, prefix = "replica")]
+    pub replica_db: ReplicatedDatabaseConfig,
+}
+
+#[test]
+fn test_duplicate_groups() {
+    let content = std::fs::read_to_string("tests/output/duplicate_groups_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "## Primary Db (ReplicatedDatabaseConfig) Configuration",
+        "",
+        "| Field Name   | Type   | Required | Default | Env | Details       |",
+        "|--------------|--------|----------|---------|-----|---------------|",
+        "| primary-host | String | Yes      | -       | -   | Database host |",
+        "",
+        "## Replica Db (ReplicatedDatabaseConfig) Configuration",
+        "",
+        "| Field Name   | Type   | Required | Default | Env | Details       |",
+        "|--------------|--------|----------|---------|-----|---------------|",
+        "| replica-host | String | Yes      | -       | -   | Database host |",
+        "",
+        "",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+
+    let content = std::fs::read_to_string("tests/output/merged_groups_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "## ReplicatedDatabaseConfig Configuration",
+        "",
+        "| Field Name   | Type   | Required | Default | Env | Details       |",
+        "|--------------|--------|----------|---------|-----|---------------|",
+        "| primary-host | String | Yes      | -       | -   | Database host |",
+        "| replica-host | String | Yes      | -       | -   | Database host |",
+        "",
+        "",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,37 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/empty_default_output.md")]
+pub struct PrefixConfig {
+    /// Prefix prepended to every key, none by default
+    #[clap(long, default_value = "")]
+    pub key_prefix: String,
+
+    /// Key separator
+    #[clap(long)]
+    pub separator: String,
+}
+
+#[test]
+fn test_empty_default_value() {
+    let config = PrefixConfig::parse_from(["test", "--separator", ":"]);
+    assert_eq!(config.key_prefix, "");
+
+    let content = std::fs::read_to_string("tests/output/empty_default_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details                                        | Group        |",
+        "|------------|--------|----------|---------|-----|------------------------------------------------|--------------|",
+        "| key-prefix | String | No       | (empty) | -   | Prefix prepended to every key, none by default | PrefixConfig |",
+        "| separator  | String | Yes      | -       | -   | Key separator                                  | PrefixConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,55 @@
+use clap::{Parser, ValueEnum};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+#[register]
+pub enum LogFormat {
+    PlainText,
+    JsonLines,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/enum_default_output.md")]
+pub struct LoggingConfig {
+    /// Log output format
+    #[clap(long, value_enum, default_value_t = LogFormat::JsonLines)]
+    pub log_format: LogFormat,
+
+    /// Fallback format for unregistered enums
+    #[clap(long, default_value_t = Level::Info)]
+    pub level: Level,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum Level {
+    Info,
+}
+
+impl std::fmt::Display for Level {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("info")
+    }
+}
+
+#[test]
+fn test_enum_default_uses_possible_value() {
+    let config = LoggingConfig::parse_from(["test"]);
+    assert_eq!(config.log_format, LogFormat::JsonLines);
+
+    let content = std::fs::read_to_string("tests/output/enum_default_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type      | Required | Default     | Env | Details                                                         | Group         |",
+        "|------------|-----------|----------|-------------|-----|-----------------------------------------------------------------|---------------|",
+        "| log-format | LogFormat | No       | json-lines  | -   | Log output format (possible values: `plain-text`, `json-lines`) | LoggingConfig |",
+        "| level      | Level     | No       | Level::Info | -   | Fallback format for unregistered enums                          | LoggingConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,53 @@
+use clap::{Args, Parser, ValueEnum};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+#[register]
+pub enum Compression {
+    /// Store files as they are
+    None,
+    /// Fast compression with gzip
+    Gzip,
+    /// Best compression ratio
+    #[clap(name = "zstandard")]
+    Zstd,
+}
+
+#[derive(Clone, Debug, Args)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct ArchiveConfig {
+    /// Archive compression
+    #[clap(long, value_enum, default_value = "gzip")]
+    pub compression: Compression,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/enum_docs_output.md")]
+pub struct BackupConfig {
+    /// Archive settings
+    #[clap(flatten)]
+    pub archive: ArchiveConfig,
+}
+
+#[test]
+fn test_enum_variant_docs_in_flattened_struct() {
+    let config = BackupConfig::parse_from(["test", "--compression", "zstandard"]);
+    assert!(matches!(config.archive.compression, Compression::Zstd));
+
+    let content = std::fs::read_to_string("tests/output/enum_docs_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name  | Type        | Required | Default | Env | Details                                                                                                                                         | Group         |",
+        "|-------------|-------------|----------|---------|-----|-------------------------------------------------------------------------------------------------------------------------------------------------|---------------|",
+        "| compression | Compression | No       | gzip    | -   | Archive compression (possible values: `none`: Store files as they are; `gzip`: Fast compression with gzip; `zstandard`: Best compression ratio) | ArchiveConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
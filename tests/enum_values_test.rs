@@ -0,0 +1,46 @@
+use clap::{Parser, ValueEnum};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub enum LogLevel {
+    Quiet,
+    Normal,
+    ScreamingLoud,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/enum_values_output.md")]
+pub struct LoggingConfig {
+    /// Log verbosity
+    #[clap(long, value_enum, default_value = "normal")]
+    pub log_level: LogLevel,
+
+    /// Verbosity of the audit log
+    #[clap(long, value_enum)]
+    pub audit_level: Option<LogLevel>,
+}
+
+#[test]
+fn test_enum_possible_values() {
+    let config = LoggingConfig::parse_from(["test", "--log-level", "screaming-loud"]);
+    assert!(matches!(config.log_level, LogLevel::ScreamingLoud));
+
+    let content = std::fs::read_to_string("tests/output/enum_values_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name  | Type     | Required | Default | Env | Details                                                                           | Group         |",
+        "|-------------|----------|----------|---------|-----|-----------------------------------------------------------------------------------|---------------|",
+        "| log-level   | LogLevel | No       | normal  | -   | Log verbosity (possible values: `quiet`, `normal`, `screaming-loud`)              | LoggingConfig |",
+        "| audit-level | LogLevel | No       | -       | -   | Verbosity of the audit log (possible values: `quiet`, `normal`, `screaming-loud`) | LoggingConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,47 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case", rename_all_env = "snake_case")]
+#[generate(target = "tests/output/env_column_output.md", format = "grouped")]
+pub struct CacheConfig {
+    /// Cache directory
+    #[clap(long, env)]
+    pub cache_dir: String,
+
+    /// Cache size in megabytes
+    #[clap(long, env = "CACHE_SIZE", default_value_t = 64)]
+    pub cache_size: u32,
+
+    /// Print cache statistics
+    #[clap(long)]
+    pub stats: bool,
+}
+
+#[test]
+fn test_env_column() {
+    std::env::set_var("cache_dir", "/tmp/cache");
+    let config = CacheConfig::parse_from(["test"]);
+    assert_eq!(config.cache_dir, "/tmp/cache");
+
+    let content = std::fs::read_to_string("tests/output/env_column_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "## CacheConfig Configuration",
+        "",
+        "| Field Name | Type   | Required | Default | Env        | Details                                                                             |",
+        "|------------|--------|----------|---------|------------|-------------------------------------------------------------------------------------|",
+        "| cache-dir  | String | Yes      | -       | cache_dir  | Cache directory                                                                     |",
+        "| cache-size | u32    | No       | 64      | CACHE_SIZE | Cache size in megabytes (default if neither `--cache-size` nor `CACHE_SIZE` is set) |",
+        "| stats      | bool   | No       | false   | -          | Print cache statistics (flag, takes no value)                                       |",
+        "",
+        "",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,41 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/env_default_precedence_output.md")]
+pub struct PrecedenceConfig {
+    /// Log level
+    #[clap(env = "LOG_LEVEL", long, default_value = "info")]
+    pub log_level: String,
+
+    /// Worker count, not settable as a flag
+    #[clap(env = "WORKERS", default_value_t = 4)]
+    pub workers: u32,
+
+    /// No environment binding, so no note
+    #[clap(long, default_value_t = 30)]
+    pub timeout: u32,
+}
+
+#[test]
+fn test_env_default_precedence_note() {
+    assert!(std::path::Path::new("tests/output/env_default_precedence_output.md").exists());
+
+    let content = std::fs::read_to_string("tests/output/env_default_precedence_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default | Env       | Details                                                              | Group            |",
+        "|------------|--------|----------|---------|-----------|----------------------------------------------------------------------|------------------|",
+        "| log-level  | String | No       | info    | LOG_LEVEL | Log level (default if neither `--log-level` nor `LOG_LEVEL` is set)  | PrecedenceConfig |",
+        "| workers    | u32    | No       | 4       | WORKERS   | Worker count, not settable as a flag (default if `WORKERS` is unset) | PrecedenceConfig |",
+        "| timeout    | u32    | No       | 30      | -         | No environment binding, so no note                                   | PrecedenceConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
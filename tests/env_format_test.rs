@@ -0,0 +1,95 @@
+use clap::{Args, Parser};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Args)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct EnvTemplateTlsConfig {
+    /// Path of the TLS certificate
+    #[clap(long, env = "TLS_CERT")]
+    pub cert: String,
+
+    /// Accepted TLS versions
+    #[clap(long, env = "TLS_VERSIONS", default_value = "1.2 1.3")]
+    pub versions: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/env_format_output.md", format = "env")]
+pub struct EnvTemplateConfig {
+    /// Server port
+    #[clap(long, env = "SERVER_PORT", default_value_t = 8080)]
+    pub port: u16,
+
+    /// Not read from the environment
+    #[clap(long)]
+    pub verbose: bool,
+
+    #[clap(flatten)]
+    pub tls: EnvTemplateTlsConfig,
+}
+
+// Not a clap parser itself: clap would reject the repeated `--cert` argument
+#[derive(Clone, Debug)]
+#[generate(
+    target = "tests/output/env_sort_output.md",
+    format = "env",
+    env_sort = true
+)]
+pub struct EnvSortConfig {
+    /// Public listener
+    #[clap_autodoc(expand, prefix = "public")]
+    pub public_tls: EnvTemplateTlsConfig,
+
+    /// Admin listener
+    #[clap_autodoc(expand, prefix = "admin")]
+    pub admin_tls: EnvTemplateTlsConfig,
+}
+
+#[test]
+fn test_env_format() {
+    let content = std::fs::read_to_string("tests/output/env_format_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "```sh",
+        "# Server port",
+        "SERVER_PORT=8080",
+        "",
+        "# Path of the TLS certificate",
+        "# TLS_CERT=<String>",
+        "",
+        "# Accepted TLS versions",
+        "TLS_VERSIONS=\"1.2 1.3\"",
+        "```",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_env_sort_deduplicates() {
+    let content = std::fs::read_to_string("tests/output/env_sort_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "```sh",
+        "# Path of the TLS certificate",
+        "# TLS_CERT=<String>",
+        "",
+        "# Accepted TLS versions",
+        "TLS_VERSIONS=\"1.2 1.3\"",
+        "```",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
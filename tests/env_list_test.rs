@@ -0,0 +1,33 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/env_list_output.md")]
+pub struct EnvListConfig {
+    /// Postgres hosts to connect to
+    #[clap(long, env = "POSTGRES_HOSTS", value_delimiter = ',')]
+    pub postgres_hosts: Vec<String>,
+}
+
+#[test]
+fn test_env_list_with_value_delimiter() {
+    std::env::set_var("POSTGRES_HOSTS", "db1,db2");
+    let config = EnvListConfig::parse_from(["test"]);
+    assert_eq!(config.postgres_hosts, ["db1", "db2"]);
+
+    let content = std::fs::read_to_string("tests/output/env_list_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name     | Type              | Required | Default | Env            | Details                                                                                            | Group         |",
+        "|----------------|-------------------|----------|---------|----------------|----------------------------------------------------------------------------------------------------|---------------|",
+        "| postgres-hosts | String (multiple) | No       | -       | POSTGRES_HOSTS | Postgres hosts to connect to (comma-separated values, on the command line and in `POSTGRES_HOSTS`) | EnvListConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
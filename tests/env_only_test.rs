@@ -0,0 +1,42 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all_env = "SCREAMING_SNAKE_CASE")]
+#[generate(target = "tests/output/env_only_output.md")]
+#[clap_autodoc(env_only)]
+pub struct WorkerConfig {
+    /// Queue to consume jobs from
+    #[clap(env = "WORKER_QUEUE")]
+    pub queue_name: String,
+
+    /// Number of concurrent jobs
+    #[clap(env, default_value_t = 4)]
+    pub max_concurrency: u32,
+
+    /// Log level
+    #[clap(env, default_value = "info")]
+    pub log_level: String,
+}
+
+#[test]
+fn test_env_only_struct() {
+    assert!(std::path::Path::new("tests/output/env_only_output.md").exists());
+
+    let content = std::fs::read_to_string("tests/output/env_only_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Env             | Type   | Required | Default | Details                                                           | Group        |",
+        "|-----------------|--------|----------|---------|-------------------------------------------------------------------|--------------|",
+        "| WORKER_QUEUE    | String | Yes      | -       | Queue to consume jobs from                                        | WorkerConfig |",
+        "| MAX_CONCURRENCY | u32    | No       | 4       | Number of concurrent jobs (default if `MAX_CONCURRENCY` is unset) | WorkerConfig |",
+        "| LOG_LEVEL       | String | No       | info    | Log level (default if `LOG_LEVEL` is unset)                       | WorkerConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,49 @@
+use clap::Parser;
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct DatabaseConfig {
+    /// Database host
+    #[clap(env = "DB_HOST", long)]
+    pub db_host: String,
+
+    /// Database port
+    #[clap(env = "DB_PORT", long, default_value_t = 5432)]
+    pub db_port: u16,
+}
+
+// Not a clap parser itself: the nested struct is only documented inline
+#[derive(Clone, Debug)]
+#[generate(target = "tests/output/expand_output.md")]
+pub struct AppSettings {
+    /// Database settings
+    #[clap_autodoc(expand)]
+    pub db: DatabaseConfig,
+
+    /// Application name
+    pub app_name: String,
+}
+
+#[test]
+fn test_expand_non_flattened_field() {
+    assert!(std::path::Path::new("tests/output/expand_output.md").exists());
+
+    let content = std::fs::read_to_string("tests/output/expand_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default | Env     | Details                                                             | Group          |",
+        "|------------|--------|----------|---------|---------|---------------------------------------------------------------------|----------------|",
+        "| db-host    | String | Yes      | -       | DB_HOST | Database host                                                       | DatabaseConfig |",
+        "| db-port    | u16    | No       | 5432    | DB_PORT | Database port (default if neither `--db-port` nor `DB_PORT` is set) | DatabaseConfig |",
+        "| app-name   | String | Yes      | -       | -       | Application name                                                    | AppSettings    |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,79 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+const CACHE: bool = true;
+const OFFLINE: bool = false;
+
+#[derive(Clone, Debug)]
+pub struct Limits {
+    pub max_jobs: u32,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/expr_default_output.md")]
+pub struct ExprDefaultConfig {
+    /// Buffer size in bytes
+    #[clap(long, default_value_t = u64::pow(2, 10))]
+    pub buffer_size: u64,
+
+    /// Server host
+    #[clap(long, default_value_t = String::from("localhost"))]
+    pub host: String,
+
+    /// Niceness of worker processes
+    #[clap(long, default_value_t = -1)]
+    pub niceness: i32,
+
+    /// Initial queue length
+    #[clap(long, default_value_t = Vec::<u8>::new().len())]
+    pub queue_length: usize,
+
+    /// Service label
+    #[clap(long, default_value_t = format!("{}-{}", 1, 2))]
+    pub label: String,
+
+    /// Read buffer size in bytes
+    #[clap(long, default_value_t = 1 << 4)]
+    pub read_buffer: u32,
+
+    /// Maximum number of parallel jobs
+    #[clap(long, default_value_t = Limits { max_jobs: 4 }.max_jobs)]
+    pub max_jobs: u32,
+
+    /// Whether the cache is used
+    #[clap(long, default_value_t = (CACHE && !OFFLINE).to_string())]
+    pub use_cache: String,
+}
+
+#[test]
+fn test_expression_defaults_are_cleaned() {
+    let config = ExprDefaultConfig::parse_from(["test"]);
+    assert_eq!(config.buffer_size, 1024);
+    assert_eq!(config.label, "1-2");
+    assert_eq!(config.read_buffer, 16);
+    assert_eq!(config.max_jobs, 4);
+    assert_eq!(config.use_cache, "true");
+
+    let content = std::fs::read_to_string("tests/output/expr_default_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name   | Type   | Required | Default                         | Env | Details                         | Group             |",
+        "|--------------|--------|----------|---------------------------------|-----|---------------------------------|-------------------|",
+        "| buffer-size  | u64    | No       | u64::pow(2, 10)                 | -   | Buffer size in bytes            | ExprDefaultConfig |",
+        "| host         | String | No       | String::from(\"localhost\")       | -   | Server host                     | ExprDefaultConfig |",
+        "| niceness     | i32    | No       | -1                              | -   | Niceness of worker processes    | ExprDefaultConfig |",
+        "| queue-length | usize  | No       | Vec::<u8>::new().len()          | -   | Initial queue length            | ExprDefaultConfig |",
+        "| label        | String | No       | format!(\"{}-{}\", 1, 2)          | -   | Service label                   | ExprDefaultConfig |",
+        "| read-buffer  | u32    | No       | 1 << 4                          | -   | Read buffer size in bytes       | ExprDefaultConfig |",
+        "| max-jobs     | u32    | No       | Limits { max_jobs: 4 }.max_jobs | -   | Maximum number of parallel jobs | ExprDefaultConfig |",
+        "| use-cache    | String | No       | (CACHE && !OFFLINE).to_string() | -   | Whether the cache is used       | ExprDefaultConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
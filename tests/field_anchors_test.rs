@@ -0,0 +1,131 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/field_anchors_output.md", field_anchors = true)]
+pub struct AnchorConfig {
+    /// Database host
+    #[clap(env = "POSTGRES_HOST", long)]
+    pub postgres_host: String,
+
+    /// Database port
+    #[clap(env = "POSTGRES_PORT", long, default_value_t = 5432)]
+    pub postgres_port: u16,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[generate(
+    target = "tests/output/field_anchors_env_only_output.md",
+    field_anchors = true
+)]
+#[clap_autodoc(env_only)]
+pub struct EnvOnlyAnchorConfig {
+    /// Log level
+    #[clap(env)]
+    pub log_level: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/field_anchors_summary_output.md",
+    format = "summary",
+    field_anchors = true
+)]
+pub struct SummaryAnchorConfig {
+    /// Listen port
+    #[clap(long, default_value_t = 8080)]
+    pub listen_port: u16,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/field_anchors_sections_output.md",
+    format = "sections",
+    field_anchors = true
+)]
+pub struct SectionsAnchorConfig {
+    /// Listen port
+    #[clap(long, default_value_t = 8080)]
+    pub listen_port: u16,
+}
+
+#[test]
+fn test_field_anchors() {
+    assert!(std::path::Path::new("tests/output/field_anchors_output.md").exists());
+
+    let content = std::fs::read_to_string("tests/output/field_anchors_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name                                  | Type   | Required | Default | Env           | Details                                                                         | Group        |",
+        "|---------------------------------------------|--------|----------|---------|---------------|---------------------------------------------------------------------------------|--------------|",
+        "| <a id=\"cfg-postgres-host\"></a>postgres-host | String | Yes      | -       | POSTGRES_HOST | Database host                                                                   | AnchorConfig |",
+        "| <a id=\"cfg-postgres-port\"></a>postgres-port | u16    | No       | 5432    | POSTGRES_PORT | Database port (default if neither `--postgres-port` nor `POSTGRES_PORT` is set) | AnchorConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_env_only_field_anchors() {
+    let content = std::fs::read_to_string("tests/output/field_anchors_env_only_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Env                                 | Type   | Required | Default | Details   | Group               |",
+        "|-------------------------------------|--------|----------|---------|-----------|---------------------|",
+        "| <a id=\"cfg-log-level\"></a>LOG_LEVEL | String | Yes      | -       | Log level | EnvOnlyAnchorConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_summary_field_anchors() {
+    let content = std::fs::read_to_string("tests/output/field_anchors_summary_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "- <a id=\"cfg-listen-port\"></a>listen-port — Listen port [u16, default 8080]",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_sections_field_anchors() {
+    let content = std::fs::read_to_string("tests/output/field_anchors_sections_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "### <a id=\"cfg-listen-port\"></a>--listen-port",
+        "",
+        "Listen port",
+        "",
+        "- Type: u16",
+        "- Default: 8080",
+        "- Env: -",
+        "- Required: No",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,43 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/field_filter_output.md",
+    field_filter = "postgres_*"
+)]
+pub struct FilterConfig {
+    /// Database host
+    #[clap(long)]
+    pub postgres_host: String,
+
+    /// Database port
+    #[clap(long, default_value_t = 5432)]
+    pub postgres_port: u16,
+
+    /// Redis host
+    #[clap(long)]
+    pub redis_host: String,
+}
+
+#[test]
+fn test_field_filter() {
+    assert!(std::path::Path::new("tests/output/field_filter_output.md").exists());
+
+    let content = std::fs::read_to_string("tests/output/field_filter_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name    | Type   | Required | Default | Env | Details       | Group        |",
+        "|---------------|--------|----------|---------|-----|---------------|--------------|",
+        "| postgres-host | String | Yes      | -       | -   | Database host | FilterConfig |",
+        "| postgres-port | u16    | No       | 5432    | -   | Database port | FilterConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,42 @@
+use clap::{ArgAction, Parser};
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/flag_output.md")]
+pub struct FlagConfig {
+    /// Verbose output
+    #[clap(long)]
+    pub verbose: bool,
+
+    /// Enable colors
+    #[clap(long, action = ArgAction::SetTrue)]
+    pub color: bool,
+
+    /// Disable the cache
+    #[clap(long, action = clap::ArgAction::SetFalse)]
+    pub no_cache: bool,
+}
+
+#[test]
+fn test_bool_fields_render_as_flags() {
+    let config = FlagConfig::parse_from(["test", "--verbose", "--no-cache"]);
+    assert!(config.verbose && !config.color && !config.no_cache);
+
+    let content = std::fs::read_to_string("tests/output/flag_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type | Required | Default | Env | Details                                  | Group      |",
+        "|------------|------|----------|---------|-----|------------------------------------------|------------|",
+        "| verbose    | bool | No       | false   | -   | Verbose output (flag, takes no value)    | FlagConfig |",
+        "| color      | bool | No       | false   | -   | Enable colors (flag, takes no value)     | FlagConfig |",
+        "| no-cache   | bool | No       | true    | -   | Disable the cache (flag, takes no value) | FlagConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -36,14 +36,14 @@ fn test_flattening_expansion() {
     let content = std::fs::read_to_string("tests/output/flattening_test_output.md").unwrap();
 
     // Expected correct behavior - flattened fields should be expanded
-    let expected = vec![
+    let expected = [
         "[//]: # (CONFIG_DOCS_START)",
         "",
-        "| Field Name    | Type   | Required | Default | Details       | Group          |",
-        "|---------------|--------|----------|---------|---------------|----------------|",
-        "| postgres-host | String | Yes      | -       | Database host | DatabaseConfig |",
-        "| postgres-port | u16    | No       | 5432    | Database port | DatabaseConfig |",
-        "| port          | u16    | No       | 8080    | Server port   | Config         |",
+        "| Field Name    | Type   | Required | Default | Env           | Details                                                                         | Group          |",
+        "|---------------|--------|----------|---------|---------------|---------------------------------------------------------------------------------|----------------|",
+        "| postgres-host | String | Yes      | -       | POSTGRES_HOST | Database host                                                                   | DatabaseConfig |",
+        "| postgres-port | u16    | No       | 5432    | POSTGRES_PORT | Database port (default if neither `--postgres-port` nor `POSTGRES_PORT` is set) | DatabaseConfig |",
+        "| port          | u16    | No       | 8080    | SERVER_PORT   | Server port (default if neither `--port` nor `SERVER_PORT` is set)              | Config         |",
         "",
         "[//]: # (CONFIG_DOCS_END)",
     ]
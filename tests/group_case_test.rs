@@ -0,0 +1,76 @@
+use clap::Parser;
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct DatabaseConfig {
+    /// Database host
+    #[clap(env = "DB_HOST", long)]
+    pub db_host: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/group_case_flat_output.md",
+    group_case = "title"
+)]
+pub struct FlatConfig {
+    /// Database configuration
+    #[clap(flatten)]
+    pub database: DatabaseConfig,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/group_case_grouped_output.md",
+    format = "grouped",
+    group_case = "title"
+)]
+pub struct GroupedConfig {
+    /// Database configuration
+    #[clap(flatten)]
+    pub database: DatabaseConfig,
+}
+
+#[test]
+fn test_title_group_case_flat() {
+    let content = std::fs::read_to_string("tests/output/group_case_flat_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default | Env     | Details       | Group           |",
+        "|------------|--------|----------|---------|---------|---------------|-----------------|",
+        "| db-host    | String | Yes      | -       | DB_HOST | Database host | Database Config |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_title_group_case_grouped() {
+    let content = std::fs::read_to_string("tests/output/group_case_grouped_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "## Database Config Configuration",
+        "",
+        "| Field Name | Type   | Required | Default | Env     | Details       |",
+        "|------------|--------|----------|---------|---------|---------------|",
+        "| db-host    | String | Yes      | -       | DB_HOST | Database host |",
+        "",
+        "",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,58 @@
+use clap::Parser;
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct DatabaseConfig {
+    /// Database host
+    #[clap(env = "DB_HOST", long)]
+    pub db_host: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/heading_offset_output.md",
+    format = "grouped",
+    heading_offset = 1
+)]
+pub struct ServiceConfig {
+    /// Database configuration
+    #[clap(flatten)]
+    pub database: DatabaseConfig,
+
+    /// Server port
+    #[clap(env = "SERVER_PORT", long, default_value_t = 8080)]
+    pub port: u16,
+}
+
+#[test]
+fn test_heading_offset() {
+    assert!(std::path::Path::new("tests/output/heading_offset_output.md").exists());
+
+    let content = std::fs::read_to_string("tests/output/heading_offset_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "### DatabaseConfig Configuration",
+        "",
+        "| Field Name | Type   | Required | Default | Env     | Details       |",
+        "|------------|--------|----------|---------|---------|---------------|",
+        "| db-host    | String | Yes      | -       | DB_HOST | Database host |",
+        "",
+        "### ServiceConfig Configuration",
+        "",
+        "| Field Name | Type | Required | Default | Env         | Details                                                            |",
+        "|------------|------|----------|---------|-------------|--------------------------------------------------------------------|",
+        "| port       | u16  | No       | 8080    | SERVER_PORT | Server port (default if neither `--port` nor `SERVER_PORT` is set) |",
+        "",
+        "",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
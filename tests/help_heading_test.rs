@@ -0,0 +1,113 @@
+use clap::{Args, Parser};
+use clap_autodoc::{generate, register};
+
+const LOGGING_HEADING: &str = "Logging";
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/help_heading_output.md", format = "grouped")]
+pub struct ServerConfig {
+    /// Worker threads
+    #[clap(long, default_value_t = 4)]
+    pub workers: u16,
+
+    /// Address to listen on
+    #[clap(long, help_heading = "Networking", default_value = "0.0.0.0")]
+    pub listen: String,
+
+    /// Port to listen on
+    #[clap(long, help_heading = "Networking", default_value_t = 8080)]
+    pub port: u16,
+
+    /// Log level
+    #[clap(long, help_heading = LOGGING_HEADING, default_value = "info")]
+    pub log_level: String,
+}
+
+#[derive(Clone, Debug, Args)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct NetConfig {
+    /// Address to bind to
+    #[arg(long, help_heading = "Networking", default_value = "127.0.0.1")]
+    pub bind_host: String,
+
+    /// Connection timeout in seconds
+    #[arg(long, default_value_t = 30)]
+    pub timeout: u32,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/help_heading_nested_output.md",
+    format = "grouped"
+)]
+pub struct ProxyConfig {
+    /// Network configuration
+    #[clap(flatten)]
+    pub net: NetConfig,
+
+    /// Port to listen on
+    #[clap(long, help_heading = "Networking", default_value_t = 8080)]
+    pub port: u16,
+}
+
+#[test]
+fn test_help_heading_groups_fields() {
+    let content = std::fs::read_to_string("tests/output/help_heading_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "## ServerConfig Configuration",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details        |",
+        "|------------|--------|----------|---------|-----|----------------|",
+        "| workers    | u16    | No       | 4       | -   | Worker threads |",
+        "| log-level  | String | No       | info    | -   | Log level      |",
+        "",
+        "## Networking Configuration",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details              |",
+        "|------------|--------|----------|---------|-----|----------------------|",
+        "| listen     | String | No       | 0.0.0.0 | -   | Address to listen on |",
+        "| port       | u16    | No       | 8080    | -   | Port to listen on    |",
+        "",
+        "",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_help_heading_inside_flattened_struct() {
+    let content = std::fs::read_to_string("tests/output/help_heading_nested_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "## Networking Configuration",
+        "",
+        "| Field Name | Type   | Required | Default   | Env | Details            |",
+        "|------------|--------|----------|-----------|-----|--------------------|",
+        "| bind-host  | String | No       | 127.0.0.1 | -   | Address to bind to |",
+        "| port       | u16    | No       | 8080      | -   | Port to listen on  |",
+        "",
+        "## NetConfig Configuration",
+        "",
+        "| Field Name | Type | Required | Default | Env | Details                       |",
+        "|------------|------|----------|---------|-----|-------------------------------|",
+        "| timeout    | u32  | No       | 30      | -   | Connection timeout in seconds |",
+        "",
+        "",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
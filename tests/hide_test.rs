@@ -0,0 +1,48 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+const HIDE_TRACING: bool = false;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/hide_output.md")]
+pub struct HideConfig {
+    /// Server port
+    #[clap(long, default_value_t = 8080)]
+    pub port: u16,
+
+    /// Internal debugging switch
+    #[clap(long, hide = true)]
+    pub debug_internals: bool,
+
+    /// Log level
+    #[clap(long, hide = false, default_value = "info")]
+    pub log_level: String,
+
+    /// Tracing endpoint
+    #[clap(long, hide = HIDE_TRACING)]
+    pub tracing_endpoint: Option<String>,
+}
+
+#[test]
+fn test_hidden_fields_are_omitted() {
+    let config = HideConfig::parse_from(["test", "--debug-internals"]);
+    assert!(config.debug_internals);
+
+    let content = std::fs::read_to_string("tests/output/hide_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name       | Type   | Required | Default | Env | Details          | Group      |",
+        "|------------------|--------|----------|---------|-----|------------------|------------|",
+        "| port             | u16    | No       | 8080    | -   | Server port      | HideConfig |",
+        "| log-level        | String | No       | info    | -   | Log level        | HideConfig |",
+        "| tracing-endpoint | String | No       | -       | -   | Tracing endpoint | HideConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,120 @@
+use clap::{Args, Parser};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Args)]
+#[register]
+pub struct HtmlDatabaseConfig {
+    /// Database host
+    #[clap(long, env = "POSTGRES_HOST")]
+    pub postgres_host: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/html_format_output.md",
+    format = "html",
+    emit_source = true
+)]
+pub struct MainConfig {
+    #[clap(flatten)]
+    pub database: HtmlDatabaseConfig,
+
+    /// Listen port
+    #[clap(long, default_value_t = 8080)]
+    pub port: u16,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/html_plain_output.md", format = "html")]
+pub struct PlainHtmlConfig {
+    /// Output files, `a|b` style separators are kept as is
+    #[clap(long)]
+    pub outputs: Vec<String>,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/html_escape_output.md",
+    format = "html",
+    type_style = "clap"
+)]
+pub struct EscapedHtmlConfig {
+    /// Keeps rows where `score < 10 && name != ""`, <b>not</b> markup
+    ///
+    /// Uses "quoted" names & ranges
+    #[clap(long, env = "FILTER", default_value = "a<b & \"c\"")]
+    pub filter: String,
+}
+
+#[test]
+fn test_html_rows_carry_source_attributes() {
+    let content = std::fs::read_to_string("tests/output/html_format_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "<table>",
+        "  <thead>",
+        "    <tr><th>Field Name</th><th>Type</th><th>Required</th><th>Default</th><th>Env</th><th>Details</th><th>Group</th></tr>",
+        "  </thead>",
+        "  <tbody>",
+        "    <tr data-struct=\"HtmlDatabaseConfig\" data-field=\"postgres_host\"><td>postgres-host</td><td>String</td><td>Yes</td><td>-</td><td>POSTGRES_HOST</td><td>Database host</td><td>HtmlDatabaseConfig</td></tr>",
+        "    <tr data-struct=\"MainConfig\" data-field=\"port\"><td>port</td><td>u16</td><td>No</td><td>8080</td><td>-</td><td>Listen port</td><td>MainConfig</td></tr>",
+        "  </tbody>",
+        "</table>",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_html_without_source_attributes() {
+    let content = std::fs::read_to_string("tests/output/html_plain_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "<table>",
+        "  <thead>",
+        "    <tr><th>Field Name</th><th>Type</th><th>Required</th><th>Default</th><th>Env</th><th>Details</th><th>Group</th></tr>",
+        "  </thead>",
+        "  <tbody>",
+        "    <tr><td>outputs</td><td>String (multiple)</td><td>No</td><td>-</td><td>-</td><td>Output files, <code>a|b</code> style separators are kept as is</td><td>PlainHtmlConfig</td></tr>",
+        "  </tbody>",
+        "</table>",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_html_escapes_cell_text() {
+    let content = std::fs::read_to_string("tests/output/html_escape_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "<table>",
+        "  <thead>",
+        "    <tr><th>Field Name</th><th>Type</th><th>Required</th><th>Default</th><th>Env</th><th>Details</th><th>Group</th></tr>",
+        "  </thead>",
+        "  <tbody>",
+        "    <tr><td>filter</td><td><code>[&lt;FILTER&gt;]</code></td><td>No</td><td>a&lt;b &amp; &quot;c&quot;</td><td>FILTER</td><td>Keeps rows where <code>score &lt; 10 &amp;&amp; name != &quot;&quot;</code>, &lt;b&gt;not&lt;/b&gt; markup<br>Uses &quot;quoted&quot; names &amp; ranges (default if neither <code>--filter</code> nor <code>FILTER</code> is set)</td><td>EscapedHtmlConfig</td></tr>",
+        "  </tbody>",
+        "</table>",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
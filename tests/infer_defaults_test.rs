@@ -0,0 +1,57 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/infer_defaults_output.md",
+    infer_defaults = true
+)]
+pub struct InferConfig {
+    /// Retry count
+    #[clap(long)]
+    pub retries: u32,
+
+    /// Service name
+    #[clap(long)]
+    pub name: String,
+
+    /// Peer addresses
+    #[clap(long)]
+    pub peers: Vec<String>,
+
+    /// Proxy URL
+    #[clap(long)]
+    pub proxy: Option<String>,
+
+    /// Enable tracing
+    #[clap(long)]
+    pub trace: bool,
+
+    /// Listen port
+    #[clap(long, default_value_t = 8080)]
+    pub port: u16,
+}
+
+#[test]
+fn test_inferred_defaults_keep_required() {
+    let content = std::fs::read_to_string("tests/output/infer_defaults_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type              | Required | Default   | Env | Details                               | Group       |",
+        "|------------|-------------------|----------|-----------|-----|---------------------------------------|-------------|",
+        "| retries    | u32               | Yes      | *(0)*     | -   | Retry count                           | InferConfig |",
+        "| name       | String            | Yes      | *(\"\")*    | -   | Service name                          | InferConfig |",
+        "| peers      | String (multiple) | No       | *(empty)* | -   | Peer addresses                        | InferConfig |",
+        "| proxy      | String            | No       | *(none)*  | -   | Proxy URL                             | InferConfig |",
+        "| trace      | bool              | No       | false     | -   | Enable tracing (flag, takes no value) | InferConfig |",
+        "| port       | u16               | No       | 8080      | -   | Listen port                           | InferConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
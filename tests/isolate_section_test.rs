@@ -0,0 +1,62 @@
+// Two crates of a workspace generating into one shared file, simulated by overriding the
+// crate name of otherwise identically named structs
+mod crate_a {
+    use clap::Parser;
+    use clap_autodoc::generate;
+
+    #[derive(Clone, Debug, Parser)]
+    #[clap(rename_all = "kebab-case")]
+    #[generate(
+        target = "tests/output/isolate_section_output.md",
+        isolate_section = true,
+        crate_name = "crate_a"
+    )]
+    pub struct Config {
+        /// Server host
+        #[clap(long)]
+        pub host: String,
+    }
+}
+
+mod crate_b {
+    use clap::Parser;
+    use clap_autodoc::generate;
+
+    #[derive(Clone, Debug, Parser)]
+    #[clap(rename_all = "kebab-case")]
+    #[generate(
+        target = "tests/output/isolate_section_output.md",
+        isolate_section = true,
+        crate_name = "crate_b"
+    )]
+    pub struct Config {
+        /// Worker threads
+        #[clap(long, default_value_t = 4)]
+        pub workers: usize,
+    }
+}
+
+#[test]
+fn test_isolated_sections_per_crate() {
+    let content = std::fs::read_to_string("tests/output/isolate_section_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START crate_a::Config)",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details     | Group  |",
+        "|------------|--------|----------|---------|-----|-------------|--------|",
+        "| host       | String | Yes      | -       | -   | Server host | Config |",
+        "",
+        "[//]: # (CONFIG_DOCS_END crate_a::Config)",
+        "[//]: # (CONFIG_DOCS_START crate_b::Config)",
+        "",
+        "| Field Name | Type  | Required | Default | Env | Details        | Group  |",
+        "|------------|-------|----------|---------|-----|----------------|--------|",
+        "| workers    | usize | No       | 4       | -   | Worker threads | Config |",
+        "",
+        "[//]: # (CONFIG_DOCS_END crate_b::Config)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
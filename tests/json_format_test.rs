@@ -0,0 +1,72 @@
+use clap::{Args, Parser};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Args)]
+#[register]
+pub struct JsonDatabaseConfig {
+    /// Database host
+    #[clap(long, env = "DATABASE_HOST")]
+    pub database_host: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/json_format_output.md", format = "json")]
+pub struct JsonConfig {
+    /// Database configuration
+    #[clap(flatten)]
+    pub database: JsonDatabaseConfig,
+
+    /// Server "public" port
+    #[clap(long, default_value_t = 8080)]
+    pub port: u16,
+
+    #[clap(long)]
+    pub label: Option<String>,
+}
+
+#[test]
+fn test_json_format() {
+    let content = std::fs::read_to_string("tests/output/json_format_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "```json",
+        "[",
+        "  {",
+        "    \"name\": \"database-host\",",
+        "    \"type\": \"String\",",
+        "    \"required\": true,",
+        "    \"default\": null,",
+        "    \"env\": \"DATABASE_HOST\",",
+        "    \"group\": \"JsonDatabaseConfig\",",
+        "    \"doc\": \"Database host\"",
+        "  },",
+        "  {",
+        "    \"name\": \"port\",",
+        "    \"type\": \"u16\",",
+        "    \"required\": false,",
+        "    \"default\": \"8080\",",
+        "    \"env\": null,",
+        "    \"group\": \"JsonConfig\",",
+        "    \"doc\": \"Server \\\"public\\\" port\"",
+        "  },",
+        "  {",
+        "    \"name\": \"label\",",
+        "    \"type\": \"String\",",
+        "    \"required\": false,",
+        "    \"default\": null,",
+        "    \"env\": null,",
+        "    \"group\": \"JsonConfig\",",
+        "    \"doc\": null",
+        "  }",
+        "]",
+        "```",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,57 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/labels_output.md",
+    required_label = "✅",
+    optional_label = "❌",
+    empty_placeholder = "n/a",
+    legend = true
+)]
+pub struct LabelsConfig {
+    /// Database host
+    #[clap(long, env = "DATABASE_HOST")]
+    pub database_host: String,
+
+    /// Database port
+    #[clap(long, default_value_t = 5432)]
+    pub database_port: u16,
+
+    /// Database user
+    #[clap(long)]
+    pub database_user: Option<String>,
+}
+
+#[test]
+fn test_custom_labels() {
+    let content = std::fs::read_to_string("tests/output/labels_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name    | Type   | Required | Default | Env           | Details       | Group        |",
+        "|---------------|--------|----------|---------|---------------|---------------|--------------|",
+        "| database-host | String | ✅       | n/a     | DATABASE_HOST | Database host | LabelsConfig |",
+        "| database-port | u16    | ❌       | 5432    | n/a           | Database port | LabelsConfig |",
+        "| database-user | String | ❌       | n/a     | n/a           | Database user | LabelsConfig |",
+        "",
+        "**Legend**",
+        "",
+        "- **Field Name**: name of the command-line argument",
+        "- **Type**: type of the value",
+        "- **Required**: `✅` if the value has to be provided, `❌` if it is optional or has a default",
+        "- **Default**: value used when the option is not set",
+        "- **Env**: environment variable the value is read from",
+        "- **Details**: description and notes on how the value is parsed",
+        "- **Group**: struct the field is declared in",
+        "- `n/a` in Env: the field has no environment variable",
+        "- `n/a` in Default: the field has no default value",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,67 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/last_args_output.md", type_style = "clap")]
+pub struct RunnerConfig {
+    /// Binary to run
+    #[clap(long)]
+    pub bin: String,
+
+    /// Arguments passed to the binary
+    #[clap(last = true, num_args = 1.., required = true)]
+    pub args: Vec<String>,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/last_args_rust_output.md")]
+pub struct OptionalRunnerConfig {
+    /// Arguments passed to the binary
+    #[clap(last = true, num_args = 1..)]
+    pub args: Vec<String>,
+}
+
+#[test]
+fn test_last_args_show_separator() {
+    let config = RunnerConfig::parse_from(["test", "--bin", "app", "--", "--verbose", "run"]);
+    assert_eq!(config.args, ["--verbose", "run"]);
+
+    let content = std::fs::read_to_string("tests/output/last_args_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type           | Required | Default | Env | Details                                                 | Group        |",
+        "|------------|----------------|----------|---------|-----|---------------------------------------------------------|--------------|",
+        "| bin        | `<BIN>`        | Yes      | -       | -   | Binary to run                                           | RunnerConfig |",
+        "| args       | `-- <ARGS>...` | Yes      | -       | -   | Arguments passed to the binary (takes 1 or more values) | RunnerConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_last_args_note_with_rust_types() {
+    let config = OptionalRunnerConfig::parse_from(["test"]);
+    assert!(config.args.is_empty());
+
+    let content = std::fs::read_to_string("tests/output/last_args_rust_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type              | Required | Default | Env | Details                                                                     | Group                |",
+        "|------------|-------------------|----------|---------|-----|-----------------------------------------------------------------------------|----------------------|",
+        "| args       | String (multiple) | No       | -       | -   | Arguments passed to the binary (passed after `--`) (takes 1 or more values) | OptionalRunnerConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
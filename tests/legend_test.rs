@@ -0,0 +1,52 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/legend_output.md",
+    type_style = "clap",
+    legend = true
+)]
+pub struct LegendConfig {
+    /// Upstream servers to proxy to
+    #[clap(long)]
+    pub upstream: Vec<String>,
+
+    /// Listen port
+    #[clap(long, default_value_t = 8080)]
+    pub port: u16,
+}
+
+#[test]
+fn test_legend_lists_used_symbols() {
+    let content = std::fs::read_to_string("tests/output/legend_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type            | Required | Default | Env | Details                      | Group        |",
+        "|------------|-----------------|----------|---------|-----|------------------------------|--------------|",
+        "| upstream   | `<UPSTREAM>...` | No       | -       | -   | Upstream servers to proxy to | LegendConfig |",
+        "| port       | `[<PORT>]`      | No       | 8080    | -   | Listen port                  | LegendConfig |",
+        "",
+        "**Legend**",
+        "",
+        "- **Field Name**: name of the command-line argument",
+        "- **Type**: type of the value",
+        "- **Required**: `Yes` if the value has to be provided, `No` if it is optional or has a default",
+        "- **Default**: value used when the option is not set",
+        "- **Env**: environment variable the value is read from",
+        "- **Details**: description and notes on how the value is parsed",
+        "- **Group**: struct the field is declared in",
+        "- `-` in Env: the field has no environment variable",
+        "- `-` in Default: the field has no default value",
+        "- `[<VALUE>]`: the value may be omitted",
+        "- `<VALUE>...`: the value may be given multiple times",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,50 @@
+use clap::{Args, Parser};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Args)]
+#[register]
+pub struct CacheConfig {
+    /// Cache address
+    #[clap(long = "cache-addr")]
+    pub cache_address: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/long_name_output.md")]
+pub struct LongNameConfig {
+    /// Database URL
+    #[clap(
+        long = "db-url",
+        env = "DATABASE_URL",
+        default_value = "postgres://localhost"
+    )]
+    pub database_url: String,
+
+    /// Cache configuration
+    #[clap(flatten)]
+    pub cache: CacheConfig,
+}
+
+#[test]
+fn test_explicit_long_names() {
+    let config = LongNameConfig::parse_from(["test", "--db-url", "db", "--cache-addr", "cache"]);
+    assert_eq!(config.database_url, "db");
+    assert_eq!(config.cache.cache_address, "cache");
+
+    let content = std::fs::read_to_string("tests/output/long_name_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default              | Env          | Details                                                                | Group          |",
+        "|------------|--------|----------|----------------------|--------------|------------------------------------------------------------------------|----------------|",
+        "| db-url     | String | No       | postgres://localhost | DATABASE_URL | Database URL (default if neither `--db-url` nor `DATABASE_URL` is set) | LongNameConfig |",
+        "| cache-addr | String | Yes      | -                    | -            | Cache address                                                          | CacheConfig    |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
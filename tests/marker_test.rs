@@ -0,0 +1,45 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/marker_output.md", marker = "DB_CONFIG")]
+pub struct MarkerDatabaseConfig {
+    /// Database host
+    #[clap(long)]
+    pub database_host: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/marker_output.md", marker = "SERVER_CONFIG")]
+pub struct MarkerServerConfig {
+    /// Server port
+    #[clap(long, default_value_t = 8080)]
+    pub server_port: u16,
+}
+
+#[test]
+fn test_custom_markers_share_a_file() {
+    let content = std::fs::read_to_string("tests/output/marker_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (DB_CONFIG_START)",
+        "",
+        "| Field Name    | Type   | Required | Default | Env | Details       | Group                |",
+        "|---------------|--------|----------|---------|-----|---------------|----------------------|",
+        "| database-host | String | Yes      | -       | -   | Database host | MarkerDatabaseConfig |",
+        "",
+        "[//]: # (DB_CONFIG_END)",
+        "[//]: # (SERVER_CONFIG_START)",
+        "",
+        "| Field Name  | Type | Required | Default | Env | Details     | Group              |",
+        "|-------------|------|----------|---------|-----|-------------|--------------------|",
+        "| server-port | u16  | No       | 8080    | -   | Server port | MarkerServerConfig |",
+        "",
+        "[//]: # (SERVER_CONFIG_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
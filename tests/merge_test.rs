@@ -0,0 +1,52 @@
+use clap::{Args, Parser};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/merge_output.md",
+    merge = [MergeDatabaseConfig, MergeCacheConfig]
+)]
+pub struct MergeServerConfig {
+    /// Server port
+    #[clap(long, default_value_t = 8080)]
+    pub port: u16,
+}
+
+#[derive(Clone, Debug, Args)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct MergeDatabaseConfig {
+    /// Database host
+    #[clap(long, env = "DATABASE_HOST")]
+    pub database_host: String,
+}
+
+#[derive(Clone, Debug, Args)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct MergeCacheConfig {
+    /// Cache size in megabytes
+    #[clap(long, default_value_t = 64)]
+    pub cache_size: u32,
+}
+
+#[test]
+fn test_merge() {
+    let content = std::fs::read_to_string("tests/output/merge_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name    | Type   | Required | Default | Env           | Details                 | Group               |",
+        "|---------------|--------|----------|---------|---------------|-------------------------|---------------------|",
+        "| port          | u16    | No       | 8080    | -             | Server port             | MergeServerConfig   |",
+        "| database-host | String | Yes      | -       | DATABASE_HOST | Database host           | MergeDatabaseConfig |",
+        "| cache-size    | u32    | No       | 64      | -             | Cache size in megabytes | MergeCacheConfig    |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,32 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/min_col_width_output.md",
+    min_col_width = "name:18,type:8,details:20"
+)]
+pub struct MinWidthConfig {
+    /// Server host
+    #[clap(long)]
+    pub host: String,
+}
+
+#[test]
+fn test_minimum_column_widths() {
+    let content = std::fs::read_to_string("tests/output/min_col_width_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name         | Type     | Required | Default | Env | Details              | Group          |",
+        "|--------------------|----------|----------|---------|-----|----------------------|----------------|",
+        "| host               | String   | Yes      | -       | -   | Server host          | MinWidthConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,51 @@
+use clap::{Args, Parser};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Args)]
+#[clap(rename_all = "snake_case")]
+#[register]
+pub struct SnakePoolConfig {
+    /// Connections kept open
+    #[clap(long, default_value_t = 5)]
+    pub pool_size: u32,
+
+    /// Seconds before an idle connection is closed
+    #[clap(long, default_value_t = 600)]
+    pub idle_timeout: u64,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "camelCase")]
+#[generate(target = "tests/output/mixed_rename_output.md")]
+pub struct CamelServiceConfig {
+    /// Retries of failed requests
+    #[clap(long, default_value_t = 3)]
+    pub max_retries: u32,
+
+    #[clap(flatten)]
+    pub pool: SnakePoolConfig,
+}
+
+#[test]
+fn test_snake_struct_flattened_into_camel_parent() {
+    // The documented names are the ones clap accepts
+    let config = CamelServiceConfig::parse_from(["test", "--maxRetries", "1", "--pool_size", "10"]);
+    assert_eq!((config.max_retries, config.pool.pool_size), (1, 10));
+
+    let content = std::fs::read_to_string("tests/output/mixed_rename_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name   | Type | Required | Default | Env | Details                                     | Group              |",
+        "|--------------|------|----------|---------|-----|---------------------------------------------|--------------------|",
+        "| maxRetries   | u32  | No       | 3       | -   | Retries of failed requests                  | CamelServiceConfig |",
+        "| pool_size    | u32  | No       | 5       | -   | Connections kept open                       | SnakePoolConfig    |",
+        "| idle_timeout | u64  | No       | 600     | -   | Seconds before an idle connection is closed | SnakePoolConfig    |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
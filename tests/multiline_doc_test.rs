@@ -0,0 +1,38 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/multiline_doc_output.md")]
+pub struct MultilineDocConfig {
+    /// Database URL.
+    /// Must include the scheme,
+    /// e.g. postgres://localhost.
+    ///
+    /// Credentials are read from the environment.
+    #[clap(long)]
+    pub database_url: String,
+
+    /// Retry attempts
+    #[clap(long, default_value_t = 3)]
+    pub retries: u32,
+}
+
+#[test]
+fn test_multiline_doc_comments_are_joined() {
+    let content = std::fs::read_to_string("tests/output/multiline_doc_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name   | Type   | Required | Default | Env | Details                                                                                                         | Group              |",
+        "|--------------|--------|----------|---------|-----|-----------------------------------------------------------------------------------------------------------------|--------------------|",
+        "| database-url | String | Yes      | -       | -   | Database URL. Must include the scheme, e.g. postgres://localhost.<br>Credentials are read from the environment. | MultilineDocConfig |",
+        "| retries      | u32    | No       | 3       | -   | Retry attempts                                                                                                  | MultilineDocConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,65 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/multiple_blocks_output.md",
+    marker = "DATABASE_DOCS"
+)]
+pub struct BlocksDatabaseConfig {
+    /// Database host
+    #[clap(long)]
+    pub database_host: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/multiple_blocks_output.md",
+    marker = "CACHE_DOCS"
+)]
+pub struct BlocksCacheConfig {
+    /// Cache size in megabytes
+    #[clap(long, default_value_t = 64)]
+    pub cache_size: u32,
+}
+
+#[test]
+fn test_marker_blocks_are_updated_in_place() {
+    let content = std::fs::read_to_string("tests/output/multiple_blocks_output.md").unwrap();
+
+    let expected = [
+        "# Services",
+        "",
+        "Each table is kept between its own markers, for example:",
+        "",
+        "```markdown",
+        "[//]: # (DATABASE_DOCS_START)",
+        "[//]: # (DATABASE_DOCS_END)",
+        "```",
+        "",
+        "## Database",
+        "",
+        "[//]: # (DATABASE_DOCS_START)",
+        "",
+        "| Field Name    | Type   | Required | Default | Env | Details       | Group                |",
+        "|---------------|--------|----------|---------|-----|---------------|----------------------|",
+        "| database-host | String | Yes      | -       | -   | Database host | BlocksDatabaseConfig |",
+        "",
+        "[//]: # (DATABASE_DOCS_END)",
+        "",
+        "## Cache",
+        "",
+        "[//]: # (CACHE_DOCS_START)",
+        "",
+        "| Field Name | Type | Required | Default | Env | Details                 | Group             |",
+        "|------------|------|----------|---------|-----|-------------------------|-------------------|",
+        "| cache-size | u32  | No       | 64      | -   | Cache size in megabytes | BlocksCacheConfig |",
+        "",
+        "[//]: # (CACHE_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,52 @@
+use clap::{Args, Parser};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Args)]
+#[register]
+pub struct NestedAliasDatabaseConfig {
+    /// Database host
+    #[clap(
+        long,
+        short = 'H',
+        alias = "db-host",
+        short_alias = 'd',
+        env = "DB_HOST"
+    )]
+    pub database_host: String,
+
+    /// Database port
+    #[clap(long = "db-port", visible_alias = "port", visible_short_alias = 'p')]
+    pub database_port: u16,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/nested_alias_output.md")]
+pub struct NestedAliasConfig {
+    /// Database configuration
+    #[clap(flatten)]
+    pub database: NestedAliasDatabaseConfig,
+}
+
+#[test]
+fn test_nested_aliases_survive_flattening() {
+    let config = NestedAliasConfig::parse_from(["test", "-d", "localhost", "--port", "5432"]);
+    assert_eq!(config.database.database_host, "localhost");
+    assert_eq!(config.database.database_port, 5432);
+
+    let content = std::fs::read_to_string("tests/output/nested_alias_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name                                  | Type   | Required | Default | Env     | Details       | Group                     |",
+        "|---------------------------------------------|--------|----------|---------|---------|---------------|---------------------------|",
+        "| database-host (hidden aliases: db-host, -d) | String | Yes      | -       | DB_HOST | Database host | NestedAliasDatabaseConfig |",
+        "| db-port (aliases: port, -p)                 | u16    | Yes      | -       | -       | Database port | NestedAliasDatabaseConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,139 @@
+use clap::{Args, Parser};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Args)]
+#[clap(rename_all = "snake_case")]
+#[register]
+pub struct StorageConfig {
+    /// Storage bucket
+    #[clap(long)]
+    pub storage_bucket: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/nested_rename_output.md")]
+pub struct ServiceConfig {
+    /// Storage configuration
+    #[clap(flatten)]
+    pub storage: StorageConfig,
+
+    /// Service name
+    #[clap(long)]
+    pub service_name: String,
+}
+
+#[test]
+fn test_nested_struct_keeps_own_rename_all() {
+    let config = ServiceConfig::parse_from([
+        "test",
+        "--storage_bucket",
+        "assets",
+        "--service-name",
+        "api",
+    ]);
+    assert_eq!(config.storage.storage_bucket, "assets");
+
+    let content = std::fs::read_to_string("tests/output/nested_rename_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name     | Type   | Required | Default | Env | Details        | Group         |",
+        "|----------------|--------|----------|---------|-----|----------------|---------------|",
+        "| storage_bucket | String | Yes      | -       | -   | Storage bucket | StorageConfig |",
+        "| service-name   | String | Yes      | -       | -   | Service name   | ServiceConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[derive(Clone, Debug, Args)]
+#[register]
+pub struct CacheConfig {
+    /// Cache size in megabytes
+    #[clap(long, env, default_value_t = 64)]
+    pub cache_size: u32,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case", rename_all_env = "SCREAMING_SNAKE_CASE")]
+#[generate(target = "tests/output/nested_rename_inherit_output.md")]
+pub struct InheritingServiceConfig {
+    /// Cache configuration
+    #[clap(flatten)]
+    pub cache: CacheConfig,
+
+    /// Storage configuration
+    #[clap(flatten)]
+    pub storage: StorageConfig,
+}
+
+#[test]
+fn test_nested_struct_without_rename_all_uses_clap_defaults() {
+    let config = InheritingServiceConfig::parse_from([
+        "test",
+        "--cache-size",
+        "128",
+        "--storage_bucket",
+        "assets",
+    ]);
+    assert_eq!(config.cache.cache_size, 128);
+
+    let content = std::fs::read_to_string("tests/output/nested_rename_inherit_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name     | Type   | Required | Default | Env        | Details                                                                             | Group         |",
+        "|----------------|--------|----------|---------|------------|-------------------------------------------------------------------------------------|---------------|",
+        "| cache-size     | u32    | No       | 64      | CACHE_SIZE | Cache size in megabytes (default if neither `--cache-size` nor `CACHE_SIZE` is set) | CacheConfig   |",
+        "| storage_bucket | String | Yes      | -       | -          | Storage bucket                                                                      | StorageConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "camelCase", rename_all_env = "kebab-case")]
+#[generate(target = "tests/output/nested_rename_camel_parent_output.md")]
+pub struct CamelServiceConfig {
+    /// Cache configuration
+    #[clap(flatten)]
+    pub cache: CacheConfig,
+
+    /// Request timeout in seconds
+    #[clap(long, env, default_value_t = 30)]
+    pub request_timeout: u32,
+}
+
+#[test]
+fn test_nested_struct_ignores_non_kebab_parent_rename_all() {
+    let config =
+        CamelServiceConfig::parse_from(["test", "--cache-size", "128", "--requestTimeout", "5"]);
+    assert_eq!(config.cache.cache_size, 128);
+    assert_eq!(config.request_timeout, 5);
+
+    let content =
+        std::fs::read_to_string("tests/output/nested_rename_camel_parent_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name     | Type | Required | Default | Env             | Details                                                                                         | Group              |",
+        "|----------------|------|----------|---------|-----------------|-------------------------------------------------------------------------------------------------|--------------------|",
+        "| cache-size     | u32  | No       | 64      | CACHE_SIZE      | Cache size in megabytes (default if neither `--cache-size` nor `CACHE_SIZE` is set)             | CacheConfig        |",
+        "| requestTimeout | u32  | No       | 30      | request-timeout | Request timeout in seconds (default if neither `--requestTimeout` nor `request-timeout` is set) | CamelServiceConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
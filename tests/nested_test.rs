@@ -79,21 +79,21 @@ fn test_nested_flat_format() {
     let content = std::fs::read_to_string("tests/output/nested_flat_output.md").unwrap();
 
     // Expected output for flat format with expanded flattened fields
-    let expected = vec![
+    let expected = [
         "[//]: # (CONFIG_DOCS_START)",
         "",
-        "| Field Name        | Type   | Required | Default        | Details       | Group            |",
-        "|-------------------|--------|----------|----------------|---------------|------------------|",
-        "| postgres-host     | String | Yes      | -              | Database host | DatabaseConfig   |",
-        "| postgres-port     | u16    | No       | 5432           | Database port | DatabaseConfig   |",
-        "| postgres-user     | String | Yes      | -              |               | DatabaseConfig   |",
-        "| postgres-password | String | Yes      | -              |               | DatabaseConfig   |",
-        "| postgres-database | String | No       | data-ingestion |               | DatabaseConfig   |",
-        "| redis-host        | String | Yes      | -              | Redis host    | RedisConfig      |",
-        "| redis-port        | u16    | No       | 6379           | Redis port    | RedisConfig      |",
-        "| port              | u16    | No       | 8080           | Server port   | NestedConfigFlat |",
+        "| Field Name        | Type   | Required | Default        | Env               | Details                                                                         | Group            |",
+        "|-------------------|--------|----------|----------------|-------------------|---------------------------------------------------------------------------------|------------------|",
+        "| postgres-host     | String | Yes      | -              | POSTGRES_HOST     | Database host                                                                   | DatabaseConfig   |",
+        "| postgres-port     | u16    | No       | 5432           | POSTGRES_PORT     | Database port (default if neither `--postgres-port` nor `POSTGRES_PORT` is set) | DatabaseConfig   |",
+        "| postgres-user     | String | Yes      | -              | POSTGRES_USER     |                                                                                 | DatabaseConfig   |",
+        "| postgres-password | String | Yes      | -              | POSTGRES_PASSWORD |                                                                                 | DatabaseConfig   |",
+        "| postgres-database | String | No       | data-ingestion | POSTGRES_DATABASE | (default if neither `--postgres-database` nor `POSTGRES_DATABASE` is set)       | DatabaseConfig   |",
+        "| redis-host        | String | Yes      | -              | REDIS_HOST        | Redis host                                                                      | RedisConfig      |",
+        "| redis-port        | u16    | No       | 6379           | REDIS_PORT        | Redis port (default if neither `--redis-port` nor `REDIS_PORT` is set)          | RedisConfig      |",
+        "| port              | u16    | No       | 8080           | SERVER_PORT       | Server port (default if neither `--port` nor `SERVER_PORT` is set)              | NestedConfigFlat |",
         "",
-        "[//]: # (CONFIG_DOCS_END)"
+        "[//]: # (CONFIG_DOCS_END)",
     ].join("\n");
 
     // Compare the generated content with expected output
@@ -106,31 +106,31 @@ fn test_nested_grouped_format() {
 
     let content = std::fs::read_to_string("tests/output/nested_grouped_output.md").unwrap();
 
-    let expected = vec![
+    let expected = [
         "[//]: # (CONFIG_DOCS_START)",
         "",
         "## DatabaseConfig Configuration",
         "",
-        "| Field Name        | Type   | Required | Default        | Details       |",
-        "|-------------------|--------|----------|----------------|---------------|",
-        "| postgres-host     | String | Yes      | -              | Database host |",
-        "| postgres-port     | u16    | No       | 5432           | Database port |",
-        "| postgres-user     | String | Yes      | -              |               |",
-        "| postgres-password | String | Yes      | -              |               |",
-        "| postgres-database | String | No       | data-ingestion |               |",
+        "| Field Name        | Type   | Required | Default        | Env               | Details                                                                         |",
+        "|-------------------|--------|----------|----------------|-------------------|---------------------------------------------------------------------------------|",
+        "| postgres-host     | String | Yes      | -              | POSTGRES_HOST     | Database host                                                                   |",
+        "| postgres-port     | u16    | No       | 5432           | POSTGRES_PORT     | Database port (default if neither `--postgres-port` nor `POSTGRES_PORT` is set) |",
+        "| postgres-user     | String | Yes      | -              | POSTGRES_USER     |                                                                                 |",
+        "| postgres-password | String | Yes      | -              | POSTGRES_PASSWORD |                                                                                 |",
+        "| postgres-database | String | No       | data-ingestion | POSTGRES_DATABASE | (default if neither `--postgres-database` nor `POSTGRES_DATABASE` is set)       |",
         "",
         "## RedisConfig Configuration",
         "",
-        "| Field Name | Type   | Required | Default | Details    |",
-        "|------------|--------|----------|---------|------------|",
-        "| redis-host | String | Yes      | -       | Redis host |",
-        "| redis-port | u16    | No       | 6379    | Redis port |",
+        "| Field Name | Type   | Required | Default | Env        | Details                                                                |",
+        "|------------|--------|----------|---------|------------|------------------------------------------------------------------------|",
+        "| redis-host | String | Yes      | -       | REDIS_HOST | Redis host                                                             |",
+        "| redis-port | u16    | No       | 6379    | REDIS_PORT | Redis port (default if neither `--redis-port` nor `REDIS_PORT` is set) |",
         "",
         "## NestedConfigGrouped Configuration",
         "",
-        "| Field Name | Type | Required | Default | Details     |",
-        "|------------|------|----------|---------|-------------|",
-        "| port       | u16  | No       | 8080    | Server port |",
+        "| Field Name | Type | Required | Default | Env         | Details                                                            |",
+        "|------------|------|----------|---------|-------------|--------------------------------------------------------------------|",
+        "| port       | u16  | No       | 8080    | SERVER_PORT | Server port (default if neither `--port` nor `SERVER_PORT` is set) |",
         "",
         "",
         "",
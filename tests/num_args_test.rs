@@ -0,0 +1,81 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/num_args_output.md")]
+pub struct ArityConfig {
+    /// Window size as width and height
+    #[clap(long, num_args = 2)]
+    pub size: Vec<u32>,
+
+    /// Hosts to connect to
+    #[clap(long, num_args = 1..)]
+    pub hosts: Vec<String>,
+
+    /// Color channels
+    #[clap(long, num_args = 1..=3)]
+    pub channels: Vec<u8>,
+
+    /// Single value, nothing to note
+    #[clap(long, num_args = 1)]
+    pub name: Option<String>,
+}
+
+macro_rules! with_arity {
+    ($arity:expr) => {
+        #[derive(Clone, Debug, Parser)]
+        #[clap(rename_all = "kebab-case")]
+        #[generate(target = "tests/output/num_args_macro_output.md")]
+        pub struct MacroArityConfig {
+            /// Tags of the build
+            #[clap(long, num_args = $arity)]
+            pub tags: Vec<String>,
+        }
+    };
+}
+
+with_arity!(2..4);
+
+#[test]
+fn test_num_args_notes() {
+    let config = ArityConfig::parse_from(["test", "--size", "80", "24", "--hosts", "a", "b"]);
+    assert_eq!(config.size, [80, 24]);
+    assert_eq!(config.hosts, ["a", "b"]);
+
+    let content = std::fs::read_to_string("tests/output/num_args_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type              | Required | Default | Env | Details                                          | Group       |",
+        "|------------|-------------------|----------|---------|-----|--------------------------------------------------|-------------|",
+        "| size       | u32 (multiple)    | No       | -       | -   | Window size as width and height (takes 2 values) | ArityConfig |",
+        "| hosts      | String (multiple) | No       | -       | -   | Hosts to connect to (takes 1 or more values)     | ArityConfig |",
+        "| channels   | u8 (multiple)     | No       | -       | -   | Color channels (takes 1 to 3 values)             | ArityConfig |",
+        "| name       | String            | No       | -       | -   | Single value, nothing to note                    | ArityConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_num_args_from_macro() {
+    let content = std::fs::read_to_string("tests/output/num_args_macro_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type              | Required | Default | Env | Details                                 | Group            |",
+        "|------------|-------------------|----------|---------|-----|-----------------------------------------|------------------|",
+        "| tags       | String (multiple) | No       | -       | -   | Tags of the build (takes 2 to 3 values) | MacroArityConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
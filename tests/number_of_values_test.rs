@@ -0,0 +1,32 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+// Legacy clap 3 spelling, still accepted by clap's derive
+#[derive(Clone, Debug, Parser)]
+#[generate(target = "tests/output/number_of_values_output.md")]
+pub struct LegacyConfig {
+    /// Point on the map
+    #[clap(long, number_of_values = 2)]
+    pub point: Vec<f64>,
+}
+
+#[test]
+fn test_number_of_values() {
+    let config = LegacyConfig::parse_from(["test", "--point", "1.5", "2.5"]);
+    assert_eq!(config.point, [1.5, 2.5]);
+
+    let content = std::fs::read_to_string("tests/output/number_of_values_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type           | Required | Default | Env | Details                           | Group        |",
+        "|------------|----------------|----------|---------|-----|-----------------------------------|--------------|",
+        "| point      | f64 (multiple) | No       | -       | -   | Point on the map (takes 2 values) | LegacyConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
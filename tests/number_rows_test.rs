@@ -0,0 +1,92 @@
+use clap::{Args, Parser};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/number_rows_output.md", number_rows = true)]
+pub struct NumberedConfig {
+    /// Server host
+    #[clap(long)]
+    pub host: String,
+
+    /// Server port
+    #[clap(long, default_value_t = 8080)]
+    pub port: u16,
+
+    /// Worker threads
+    #[clap(long, default_value_t = 4)]
+    pub workers: u16,
+}
+
+#[derive(Clone, Debug, Args)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct NumberedCacheConfig {
+    /// Cache size in megabytes
+    #[clap(long, default_value_t = 64)]
+    pub cache_size: u32,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/number_rows_continuous_output.md",
+    format = "grouped",
+    number_rows = "continuous"
+)]
+pub struct NumberedGroupedConfig {
+    /// Server host
+    #[clap(long)]
+    pub host: String,
+
+    #[clap(flatten)]
+    pub cache: NumberedCacheConfig,
+}
+
+#[test]
+fn test_number_rows() {
+    let content = std::fs::read_to_string("tests/output/number_rows_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| # | Field Name | Type   | Required | Default | Env | Details        | Group          |",
+        "|---|------------|--------|----------|---------|-----|----------------|----------------|",
+        "| 1 | host       | String | Yes      | -       | -   | Server host    | NumberedConfig |",
+        "| 2 | port       | u16    | No       | 8080    | -   | Server port    | NumberedConfig |",
+        "| 3 | workers    | u16    | No       | 4       | -   | Worker threads | NumberedConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_number_rows_continuous() {
+    let content = std::fs::read_to_string("tests/output/number_rows_continuous_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "## NumberedGroupedConfig Configuration",
+        "",
+        "| # | Field Name | Type   | Required | Default | Env | Details     |",
+        "|---|------------|--------|----------|---------|-----|-------------|",
+        "| 1 | host       | String | Yes      | -       | -   | Server host |",
+        "",
+        "## NumberedCacheConfig Configuration",
+        "",
+        "| # | Field Name | Type | Required | Default | Env | Details                 |",
+        "|---|------------|------|----------|---------|-----|-------------------------|",
+        "| 2 | cache-size | u32  | No       | 64      | -   | Cache size in megabytes |",
+        "",
+        "",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
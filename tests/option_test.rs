@@ -0,0 +1,91 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/option_flat_output.md")]
+#[generate(target = "tests/output/option_grouped_output.md", format = "grouped")]
+pub struct OptionConfig {
+    /// Proxy URL
+    #[clap(long)]
+    pub proxy: Option<String>,
+
+    /// Proxy port
+    #[clap(long)]
+    pub proxy_port: Option<u16>,
+
+    /// Upstream host
+    #[clap(long)]
+    pub upstream: String,
+}
+
+// clap's derive only recognizes a bare `Option`, so this struct is not parsed by clap
+#[derive(Clone, Debug)]
+#[generate(target = "tests/output/option_qualified_output.md")]
+pub struct QualifiedOptionConfig {
+    /// Request timeout in seconds
+    pub timeout: std::option::Option<u64>,
+
+    /// Client certificate
+    pub client_cert: core::option::Option<std::path::PathBuf>,
+}
+
+#[test]
+fn test_option_fields_are_optional() {
+    let config = OptionConfig::parse_from(["test", "--upstream", "example.com"]);
+    assert!(config.proxy.is_none());
+
+    let content = std::fs::read_to_string("tests/output/option_flat_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details       | Group        |",
+        "|------------|--------|----------|---------|-----|---------------|--------------|",
+        "| proxy      | String | No       | -       | -   | Proxy URL     | OptionConfig |",
+        "| proxy-port | u16    | No       | -       | -   | Proxy port    | OptionConfig |",
+        "| upstream   | String | Yes      | -       | -   | Upstream host | OptionConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+
+    let content = std::fs::read_to_string("tests/output/option_grouped_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "## OptionConfig Configuration",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details       |",
+        "|------------|--------|----------|---------|-----|---------------|",
+        "| proxy      | String | No       | -       | -   | Proxy URL     |",
+        "| proxy-port | u16    | No       | -       | -   | Proxy port    |",
+        "| upstream   | String | Yes      | -       | -   | Upstream host |",
+        "",
+        "",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+
+    let content = std::fs::read_to_string("tests/output/option_qualified_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name  | Type               | Required | Default | Env | Details                    | Group                 |",
+        "|-------------|--------------------|----------|---------|-----|----------------------------|-----------------------|",
+        "| timeout     | u64                | No       | -       | -   | Request timeout in seconds | QualifiedOptionConfig |",
+        "| client-cert | std::path::PathBuf | No       | -       | -   | Client certificate         | QualifiedOptionConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,36 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/path_base_output.md", path_base = "manifest")]
+pub struct PathBaseConfig {
+    /// Database host
+    #[clap(long)]
+    pub database_host: String,
+}
+
+#[test]
+fn test_target_relative_to_manifest() {
+    // The generated file does not depend on where it is looked up from
+    std::env::set_current_dir("tests/output").unwrap();
+    let path = concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/tests/output/path_base_output.md"
+    );
+
+    let content = std::fs::read_to_string(path).unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name    | Type   | Required | Default | Env | Details       | Group          |",
+        "|---------------|--------|----------|---------|-----|---------------|----------------|",
+        "| database-host | String | Yes      | -       | -   | Database host | PathBaseConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,49 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/pipe_escape_output.md")]
+pub struct PipeConfig {
+    /// Output mode, one of `json|yaml|text`
+    #[clap(long, default_value = "json|text")]
+    pub mode: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/pipe_escape_output.json", format = "json")]
+pub struct PipeJsonConfig {
+    /// Output mode, one of `json|yaml|text`
+    #[clap(long)]
+    pub mode: String,
+}
+
+#[test]
+fn test_pipes_are_escaped_in_tables() {
+    let content = std::fs::read_to_string("tests/output/pipe_escape_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default    | Env | Details                                | Group      |",
+        "|------------|--------|----------|------------|-----|----------------------------------------|------------|",
+        "| mode       | String | No       | json\\|text | -   | Output mode, one of `json\\|yaml\\|text` | PipeConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+
+    // Every row still has as many cells as the header
+    let rows: Vec<usize> = content
+        .lines()
+        .filter(|line| line.starts_with('|'))
+        .map(|line| line.replace("\\|", "").matches('|').count())
+        .collect();
+    assert!(rows.iter().all(|cells| *cells == rows[0]));
+
+    let json = std::fs::read_to_string("tests/output/pipe_escape_output.json").unwrap();
+    assert!(json.contains(r#""doc": "Output mode, one of `json|yaml|text`""#));
+}
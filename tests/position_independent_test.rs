@@ -56,16 +56,16 @@ fn test_position_independence() {
 
     let content = std::fs::read_to_string("tests/output/position_independent_output.md").unwrap();
 
-    let expected = vec![
+    let expected = [
         "[//]: # (CONFIG_DOCS_START)",
         "",
-        "| Field Name | Type   | Required | Default | Details              | Group          |",
-        "|------------|--------|----------|---------|----------------------|----------------|",
-        "| port       | u16    | No       | 8080    | Server port          | MainConfig     |",
-        "| db-host    | String | Yes      | -       | Database host        | DatabaseConfig |",
-        "| db-port    | u16    | No       | 5432    | Database port        | DatabaseConfig |",
-        "| cache-host | String | Yes      | -       | Cache host           | CacheConfig    |",
-        "| cache-ttl  | u32    | No       | 3600    | Cache TTL in seconds | CacheConfig    |",
+        "| Field Name | Type   | Required | Default | Env         | Details                                                                        | Group          |",
+        "|------------|--------|----------|---------|-------------|--------------------------------------------------------------------------------|----------------|",
+        "| port       | u16    | No       | 8080    | SERVER_PORT | Server port (default if neither `--port` nor `SERVER_PORT` is set)             | MainConfig     |",
+        "| db-host    | String | Yes      | -       | DB_HOST     | Database host                                                                  | DatabaseConfig |",
+        "| db-port    | u16    | No       | 5432    | DB_PORT     | Database port (default if neither `--db-port` nor `DB_PORT` is set)            | DatabaseConfig |",
+        "| cache-host | String | Yes      | -       | CACHE_HOST  | Cache host                                                                     | CacheConfig    |",
+        "| cache-ttl  | u32    | No       | 3600    | CACHE_TTL   | Cache TTL in seconds (default if neither `--cache-ttl` nor `CACHE_TTL` is set) | CacheConfig    |",
         "",
         "[//]: # (CONFIG_DOCS_END)",
     ]
@@ -0,0 +1,72 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[generate(
+    target = "tests/output/positional_required_output.md",
+    type_style = "clap"
+)]
+pub struct RequiredPositionals {
+    /// Input file
+    pub file: String,
+
+    /// Files to merge
+    #[clap(required = true, num_args = 1..)]
+    pub merge: Vec<String>,
+}
+
+const REQUIRE_OUTPUT: bool = false;
+
+#[derive(Clone, Debug, Parser)]
+#[generate(
+    target = "tests/output/positional_optional_output.md",
+    type_style = "clap"
+)]
+pub struct OptionalPositionals {
+    /// Output file
+    #[clap(required = REQUIRE_OUTPUT)]
+    pub output: Option<String>,
+
+    /// Files to include
+    pub include: Vec<String>,
+}
+
+#[test]
+fn test_positional_cardinalities() {
+    let config = RequiredPositionals::parse_from(["test", "in", "a", "b"]);
+    assert_eq!(config.merge, ["a", "b"]);
+    assert!(RequiredPositionals::try_parse_from(["test", "in"]).is_err());
+    assert!(OptionalPositionals::parse_from(["test"]).include.is_empty());
+
+    let content = std::fs::read_to_string("tests/output/positional_required_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type         | Required | Default | Env | Details                                 | Group               |",
+        "|------------|--------------|----------|---------|-----|-----------------------------------------|---------------------|",
+        "| file       | `<FILE>`     | Yes      | -       | -   | Input file                              | RequiredPositionals |",
+        "| merge      | `<MERGE>...` | Yes      | -       | -   | Files to merge (takes 1 or more values) | RequiredPositionals |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+
+    let content = std::fs::read_to_string("tests/output/positional_optional_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type           | Required | Default | Env | Details          | Group               |",
+        "|------------|----------------|----------|---------|-----|------------------|---------------------|",
+        "| output     | `[OUTPUT]`     | No       | -       | -   | Output file      | OptionalPositionals |",
+        "| include    | `[INCLUDE]...` | No       | -       | -   | Files to include | OptionalPositionals |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
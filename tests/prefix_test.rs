@@ -0,0 +1,46 @@
+use clap::Parser;
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct ConnectionConfig {
+    /// Host to connect to
+    #[clap(long)]
+    pub host: String,
+
+    /// Port to connect to
+    #[clap(long, default_value_t = 5432)]
+    pub port: u16,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/prefix_output.md")]
+pub struct PrefixConfig {
+    /// Database connection
+    #[clap(flatten)]
+    #[clap_autodoc(prefix = "db")]
+    pub database: ConnectionConfig,
+}
+
+#[test]
+fn test_prefixed_flattened_group() {
+    assert!(std::path::Path::new("tests/output/prefix_output.md").exists());
+
+    let content = std::fs::read_to_string("tests/output/prefix_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details            | Group            |",
+        "|------------|--------|----------|---------|-----|--------------------|------------------|",
+        "| db-host    | String | Yes      | -       | -   | Host to connect to | ConnectionConfig |",
+        "| db-port    | u16    | No       | 5432    | -   | Port to connect to | ConnectionConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
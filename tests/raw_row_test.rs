@@ -0,0 +1,39 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/raw_row_output.md")]
+pub struct RawRowConfig {
+    /// Server port
+    #[clap(env = "SERVER_PORT", long, default_value_t = 8080)]
+    pub port: u16,
+
+    /// Worker threads
+    #[clap(env = "WORKERS", long, default_value_t = 0)]
+    #[clap_autodoc(
+        raw_row = "| workers | usize | No | number of CPUs | WORKERS | Worker threads, `0` means one per CPU | RawRowConfig |"
+    )]
+    pub workers: usize,
+}
+
+#[test]
+fn test_raw_row() {
+    assert!(std::path::Path::new("tests/output/raw_row_output.md").exists());
+
+    let content = std::fs::read_to_string("tests/output/raw_row_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type  | Required | Default        | Env         | Details                                                            | Group        |",
+        "|------------|-------|----------|----------------|-------------|--------------------------------------------------------------------|--------------|",
+        "| port       | u16   | No       | 8080           | SERVER_PORT | Server port (default if neither `--port` nor `SERVER_PORT` is set) | RawRowConfig |",
+        "| workers    | usize | No       | number of CPUs | WORKERS     | Worker threads, `0` means one per CPU                              | RawRowConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
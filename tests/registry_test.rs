@@ -13,6 +13,7 @@ pub struct SimpleConfig {
 #[test]
 fn test_registration_macro() {
     // This test just verifies that the registration macro compiles
-    // and doesn't panic during compilation
-    assert!(true);
+    // and doesn't panic during compilation, leaving the struct intact
+    let config = SimpleConfig::parse_from(["test", "--test-field", "value"]);
+    assert_eq!(config.test_field, "value");
 }
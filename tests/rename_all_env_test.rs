@@ -0,0 +1,41 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case", rename_all_env = "kebab-case")]
+#[generate(target = "tests/output/rename_all_env_output.md")]
+pub struct DerivedEnvConfig {
+    /// Upstream URL
+    #[clap(long = "upstream", env)]
+    pub upstream_url: String,
+
+    /// Request timeout in seconds
+    #[clap(long, env, name = "timeout", default_value_t = 30)]
+    pub request_timeout: u64,
+}
+
+#[test]
+fn test_bare_env_follows_rename_all_env() {
+    // clap derives the variable from the field identifier, not from `long` or `name`
+    std::env::set_var("upstream-url", "http://localhost");
+    std::env::set_var("request-timeout", "5");
+    let config = DerivedEnvConfig::parse_from(["test"]);
+    assert_eq!(config.upstream_url, "http://localhost");
+    assert_eq!(config.request_timeout, 5);
+
+    let content = std::fs::read_to_string("tests/output/rename_all_env_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name      | Type   | Required | Default | Env             | Details                                                                                          | Group            |",
+        "|-----------------|--------|----------|---------|-----------------|--------------------------------------------------------------------------------------------------|------------------|",
+        "| upstream        | String | Yes      | -       | upstream-url    | Upstream URL                                                                                     | DerivedEnvConfig |",
+        "| request-timeout | u64    | No       | 30      | request-timeout | Request timeout in seconds (default if neither `--request-timeout` nor `request-timeout` is set) | DerivedEnvConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
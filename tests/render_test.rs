@@ -0,0 +1,52 @@
+use clap::{Args, Parser};
+use clap_autodoc::{register, render};
+
+#[derive(Clone, Debug, Args)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct RenderDatabaseConfig {
+    /// Database host
+    #[clap(long, env = "DATABASE_HOST")]
+    pub database_host: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct RenderConfig {
+    /// Worker threads
+    #[clap(long, default_value_t = 4)]
+    pub workers: u16,
+
+    #[clap(flatten)]
+    pub database: RenderDatabaseConfig,
+}
+
+#[test]
+fn test_render() {
+    let docs: &str = render!(RenderConfig);
+
+    let expected = [
+        "| Field Name    | Type   | Required | Default | Env           | Details        | Group                |",
+        "|---------------|--------|----------|---------|---------------|----------------|----------------------|",
+        "| workers       | u16    | No       | 4       | -             | Worker threads | RenderConfig         |",
+        "| database-host | String | Yes      | -       | DATABASE_HOST | Database host  | RenderDatabaseConfig |",
+    ]
+    .join("\n");
+
+    assert_eq!(docs.trim(), expected.trim());
+}
+
+#[test]
+fn test_render_with_options() {
+    let docs: &str = render!(
+        RenderConfig,
+        format = "summary",
+        field_filter = "database_*"
+    );
+
+    let expected =
+        ["- database-host — Database host [String, required, env DATABASE_HOST]"].join("\n");
+
+    assert_eq!(docs.trim(), expected.trim());
+}
@@ -0,0 +1,51 @@
+use clap::Parser;
+use clap_autodoc::{generate, register};
+
+mod docs {
+    use clap_autodoc::register;
+
+    #[register]
+    pub const THREADS_ROW: &str =
+        "| threads | usize | No | number of CPUs | - | Worker threads, `0` means one per CPU | RenderWithConfig |";
+}
+
+#[register]
+static TIMEOUT_ROW: &str =
+    "| timeout | u64 | No | 30 | - | Request timeout in seconds | RenderWithConfig |";
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/render_with_output.md")]
+pub struct RenderWithConfig {
+    /// Worker threads
+    #[clap(long, default_value_t = 0)]
+    #[clap_autodoc(render_with = "crate::docs::THREADS_ROW")]
+    pub threads: usize,
+
+    /// Request timeout
+    #[clap(long, default_value_t = 30)]
+    #[clap_autodoc(render_with = "TIMEOUT_ROW")]
+    pub timeout: u64,
+}
+
+#[test]
+fn test_render_with_registered_constant() {
+    assert!(docs::THREADS_ROW.starts_with("| threads"));
+    assert!(TIMEOUT_ROW.starts_with("| timeout"));
+
+    let content = std::fs::read_to_string("tests/output/render_with_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type  | Required | Default        | Env | Details                               | Group            |",
+        "|------------|-------|----------|----------------|-----|---------------------------------------|------------------|",
+        "| threads    | usize | No       | number of CPUs | -   | Worker threads, `0` means one per CPU | RenderWithConfig |",
+        "| timeout    | u64   | No       | 30             | -   | Request timeout in seconds            | RenderWithConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
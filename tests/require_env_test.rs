@@ -0,0 +1,45 @@
+use clap::{Args, Parser};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Args)]
+#[register]
+pub struct RequireEnvDatabaseConfig {
+    /// Database URL
+    #[clap(long, env = "DATABASE_URL")]
+    pub database_url: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case", rename_all_env = "SCREAMING_SNAKE_CASE")]
+#[generate(target = "tests/output/require_env_output.md", require_env = true)]
+pub struct RequireEnvConfig {
+    #[clap(flatten)]
+    pub database: RequireEnvDatabaseConfig,
+
+    /// Listen port
+    #[clap(long, env, default_value_t = 8080)]
+    pub port: u16,
+
+    /// Internal state, not an argument
+    #[clap(skip)]
+    pub state: u8,
+}
+
+#[test]
+fn test_require_env_accepts_bound_fields() {
+    let content = std::fs::read_to_string("tests/output/require_env_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name   | Type   | Required | Default | Env          | Details                                                     | Group                    |",
+        "|--------------|--------|----------|---------|--------------|-------------------------------------------------------------|--------------------------|",
+        "| database-url | String | Yes      | -       | DATABASE_URL | Database URL                                                | RequireEnvDatabaseConfig |",
+        "| port         | u16    | No       | 8080    | PORT         | Listen port (default if neither `--port` nor `PORT` is set) | RequireEnvConfig         |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,60 @@
+use clap::{Args, Parser};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Args)]
+#[group(required = true, multiple = false)]
+#[register]
+pub struct SourceConfig {
+    /// Read the config from a file
+    #[clap(long)]
+    pub config_file: Option<String>,
+
+    /// Read the config from a URL
+    #[clap(long)]
+    pub config_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/required_group_output.md", format = "grouped")]
+pub struct LoaderConfig {
+    #[clap(flatten)]
+    pub source: SourceConfig,
+
+    /// Reload on change
+    #[clap(long)]
+    pub watch: bool,
+}
+
+#[test]
+fn test_required_group_heading() {
+    assert!(LoaderConfig::try_parse_from(["test"]).is_err());
+    let config = LoaderConfig::parse_from(["test", "--config-file", "app.toml"]);
+    assert_eq!(config.source.config_file.as_deref(), Some("app.toml"));
+
+    let content = std::fs::read_to_string("tests/output/required_group_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "## SourceConfig Configuration (required group)",
+        "",
+        "| Field Name  | Type   | Required | Default | Env | Details                     |",
+        "|-------------|--------|----------|---------|-----|-----------------------------|",
+        "| config-file | String | No       | -       | -   | Read the config from a file |",
+        "| config-url  | String | No       | -       | -   | Read the config from a URL  |",
+        "",
+        "## LoaderConfig Configuration",
+        "",
+        "| Field Name | Type | Required | Default | Env | Details                                 |",
+        "|------------|------|----------|---------|-----|-----------------------------------------|",
+        "| watch      | bool | No       | false   | -   | Reload on change (flag, takes no value) |",
+        "",
+        "",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,59 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+const STDIN_ID: &str = "stdin";
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/required_unless_output.md")]
+pub struct InputConfig {
+    /// Input file
+    #[clap(index = 1, required_unless_present = "stdin")]
+    pub input: Option<String>,
+
+    /// Read input from stdin
+    #[clap(long)]
+    pub stdin: bool,
+
+    /// Output file
+    #[clap(long, required_unless_present_any = ["dry_run", "stdout"])]
+    pub output: Option<String>,
+
+    /// Only print what would be done
+    #[clap(long)]
+    pub dry_run: bool,
+
+    /// Write output to stdout
+    #[clap(long)]
+    pub stdout: bool,
+
+    /// Log file
+    #[clap(long, required_unless_present = STDIN_ID)]
+    pub log_file: Option<String>,
+}
+
+#[test]
+fn test_required_unless_present() {
+    let config = InputConfig::parse_from(["test", "--stdin", "--dry-run"]);
+    assert!(config.input.is_none());
+
+    let content = std::fs::read_to_string("tests/output/required_unless_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details                                                             | Group       |",
+        "|------------|--------|----------|---------|-----|---------------------------------------------------------------------|-------------|",
+        "| input      | String | No       | -       | -   | Input file (required unless `--stdin` is set)                       | InputConfig |",
+        "| stdin      | bool   | No       | false   | -   | Read input from stdin (flag, takes no value)                        | InputConfig |",
+        "| output     | String | No       | -       | -   | Output file (required unless any of `--dry-run`, `--stdout` is set) | InputConfig |",
+        "| dry-run    | bool   | No       | false   | -   | Only print what would be done (flag, takes no value)                | InputConfig |",
+        "| stdout     | bool   | No       | false   | -   | Write output to stdout (flag, takes no value)                       | InputConfig |",
+        "| log-file   | String | No       | -       | -   | Log file                                                            | InputConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
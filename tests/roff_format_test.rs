@@ -0,0 +1,86 @@
+use clap::{Args, Parser, ValueEnum};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, ValueEnum)]
+#[register]
+pub enum RoffLogFormat {
+    Text,
+    Json,
+}
+
+#[derive(Clone, Debug, Args)]
+#[register]
+pub struct RoffDatabaseConfig {
+    /// Database host
+    #[clap(long = "db-host", env = "DB_HOST", default_value = "localhost")]
+    pub host: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/roff_format_output.1", format = "roff")]
+pub struct RoffConfig {
+    /// Listen port
+    ///
+    /// .Ports below 1024 need privileges
+    #[clap(long, default_value_t = 8080)]
+    pub listen_port: u16,
+
+    /// Log format
+    #[clap(long, value_enum, default_value_t = RoffLogFormat::Text)]
+    pub log_format: RoffLogFormat,
+
+    /// Print more output
+    #[clap(long)]
+    pub verbose: bool,
+
+    /// Files to serve
+    pub files: Vec<String>,
+
+    #[clap(flatten)]
+    pub database: RoffDatabaseConfig,
+}
+
+#[test]
+fn test_roff_format() {
+    // The page around the markers is written by hand and kept as is
+    let content = std::fs::read_to_string("tests/output/roff_format_output.1").unwrap();
+
+    let expected = [
+        ".TH ROFF 1",
+        ".SH NAME",
+        "roff \\- serve files",
+        ".SH OPTIONS",
+        ".\\\" CONFIG_DOCS_START",
+        ".SS \"RoffConfig Configuration\"",
+        ".TP",
+        ".BI \"\\-\\-listen\\-port\" \" <LISTEN_PORT>\"",
+        "Listen port",
+        ".IP",
+        "\\&.Ports below 1024 need privileges",
+        "[default: 8080]",
+        ".TP",
+        ".BI \"\\-\\-log\\-format\" \" <LOG_FORMAT>\"",
+        "Log format",
+        "[default: text]",
+        "[possible values: text, json]",
+        ".TP",
+        ".B \"\\-\\-verbose\"",
+        "Print more output",
+        ".TP",
+        ".I \"<FILES>...\"",
+        "Files to serve",
+        ".SS \"RoffDatabaseConfig Configuration\"",
+        ".TP",
+        ".BI \"\\-\\-db\\-host\" \" <HOST>\"",
+        "Database host",
+        "[default: localhost]",
+        "[env: DB_HOST]",
+        ".\\\" CONFIG_DOCS_END",
+        ".SH SEE ALSO",
+        "clap(1)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,63 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/sections_format_output.md", format = "sections")]
+pub struct SectionsConfig {
+    /// Database host
+    ///
+    /// Either a hostname or an IP address.
+    #[clap(long, env = "POSTGRES_HOST")]
+    pub postgres_host: String,
+
+    /// Database port
+    #[clap(long, default_value_t = 5432)]
+    pub postgres_port: u16,
+
+    /// Database to connect to
+    pub database: Option<String>,
+}
+
+#[test]
+fn test_sections_format() {
+    let content = std::fs::read_to_string("tests/output/sections_format_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "### --postgres-host",
+        "",
+        "Database host",
+        "",
+        "Either a hostname or an IP address.",
+        "",
+        "- Type: String",
+        "- Default: -",
+        "- Env: POSTGRES_HOST",
+        "- Required: Yes",
+        "",
+        "### --postgres-port",
+        "",
+        "Database port",
+        "",
+        "- Type: u16",
+        "- Default: 5432",
+        "- Env: -",
+        "- Required: No",
+        "",
+        "### database",
+        "",
+        "Database to connect to",
+        "",
+        "- Type: String",
+        "- Default: -",
+        "- Env: -",
+        "- Required: No",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,54 @@
+use clap::{Args, Parser};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Args)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct PoolConfig {
+    /// Pool size
+    #[clap(long, default_value_t = 8)]
+    pub pool_size: u32,
+
+    /// Connections created so far
+    #[clap(skip)]
+    pub created: u32,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/skip_output.md")]
+pub struct SkipConfig {
+    /// Server host
+    #[clap(long)]
+    pub host: String,
+
+    /// Resolved address, filled in after parsing
+    #[clap(skip)]
+    pub resolved_addr: Option<String>,
+
+    /// Pool configuration
+    #[clap(flatten)]
+    pub pool: PoolConfig,
+}
+
+#[test]
+fn test_skipped_fields_are_excluded() {
+    let config = SkipConfig::parse_from(["test", "--host", "localhost"]);
+    assert!(config.resolved_addr.is_none());
+
+    let content = std::fs::read_to_string("tests/output/skip_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details     | Group      |",
+        "|------------|--------|----------|---------|-----|-------------|------------|",
+        "| host       | String | Yes      | -       | -   | Server host | SkipConfig |",
+        "| pool-size  | u32    | No       | 8       | -   | Pool size   | PoolConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
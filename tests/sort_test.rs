@@ -0,0 +1,114 @@
+use clap::{Args, Parser};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Args)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct SortedCacheConfig {
+    /// Cache size in megabytes
+    #[clap(long, default_value_t = 64)]
+    pub size: u32,
+
+    /// Cache entry lifetime in seconds
+    #[clap(long, default_value_t = 300)]
+    pub ttl: u64,
+
+    /// Cache backend
+    #[clap(long, default_value = "memory")]
+    pub backend: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/sort_output.md", sort = "alpha")]
+pub struct SortedConfig {
+    /// Worker threads
+    #[clap(long, default_value_t = 4)]
+    pub workers: u16,
+
+    /// Server host
+    #[clap(long = "bind-address")]
+    pub host: String,
+
+    /// Server port
+    #[clap(long, default_value_t = 8080)]
+    pub port: u16,
+
+    #[clap(flatten)]
+    pub cache: SortedCacheConfig,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/sort_groups_output.md",
+    format = "grouped",
+    sort = "alpha",
+    sort_groups = true
+)]
+pub struct ZSortedConfig {
+    /// Worker threads
+    #[clap(long, default_value_t = 4)]
+    pub workers: u16,
+
+    /// Server port
+    #[clap(long, default_value_t = 8080)]
+    pub port: u16,
+
+    #[clap(flatten)]
+    pub cache: SortedCacheConfig,
+}
+
+#[test]
+fn test_sort_alpha() {
+    let content = std::fs::read_to_string("tests/output/sort_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name   | Type   | Required | Default | Env | Details                         | Group             |",
+        "|--------------|--------|----------|---------|-----|---------------------------------|-------------------|",
+        "| bind-address | String | Yes      | -       | -   | Server host                     | SortedConfig      |",
+        "| port         | u16    | No       | 8080    | -   | Server port                     | SortedConfig      |",
+        "| workers      | u16    | No       | 4       | -   | Worker threads                  | SortedConfig      |",
+        "| backend      | String | No       | memory  | -   | Cache backend                   | SortedCacheConfig |",
+        "| size         | u32    | No       | 64      | -   | Cache size in megabytes         | SortedCacheConfig |",
+        "| ttl          | u64    | No       | 300     | -   | Cache entry lifetime in seconds | SortedCacheConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_sort_groups() {
+    let content = std::fs::read_to_string("tests/output/sort_groups_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "## SortedCacheConfig Configuration",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details                         |",
+        "|------------|--------|----------|---------|-----|---------------------------------|",
+        "| backend    | String | No       | memory  | -   | Cache backend                   |",
+        "| size       | u32    | No       | 64      | -   | Cache size in megabytes         |",
+        "| ttl        | u64    | No       | 300     | -   | Cache entry lifetime in seconds |",
+        "",
+        "## ZSortedConfig Configuration",
+        "",
+        "| Field Name | Type | Required | Default | Env | Details        |",
+        "|------------|------|----------|---------|-----|----------------|",
+        "| port       | u16  | No       | 8080    | -   | Server port    |",
+        "| workers    | u16  | No       | 4       | -   | Worker threads |",
+        "",
+        "",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,80 @@
+use clap::Parser;
+use clap_autodoc::{generate, register, written_paths};
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct DatabaseConfig {
+    /// Database host
+    #[clap(long)]
+    pub postgres_host: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    format = "grouped",
+    split_by_group = true,
+    split_target_template = "tests/output/split/{group}.md"
+)]
+pub struct SplitConfig {
+    /// Database configuration
+    #[clap(flatten)]
+    pub database: DatabaseConfig,
+
+    /// Server port
+    #[clap(long, default_value_t = 8080)]
+    pub port: u16,
+}
+
+#[test]
+fn test_split_by_group_writes_file_per_group() {
+    let paths: &[&str] = written_paths!(SplitConfig);
+    assert_eq!(
+        paths,
+        [
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/output/split/database-config.md"
+            ),
+            concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/output/split/split-config.md"
+            )
+        ]
+    );
+
+    let content = std::fs::read_to_string("tests/output/split/database-config.md").unwrap();
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "## DatabaseConfig Configuration",
+        "",
+        "| Field Name    | Type   | Required | Default | Env | Details       |",
+        "|---------------|--------|----------|---------|-----|---------------|",
+        "| postgres-host | String | Yes      | -       | -   | Database host |",
+        "",
+        "",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+    assert_eq!(content.trim(), expected.trim());
+
+    let content = std::fs::read_to_string("tests/output/split/split-config.md").unwrap();
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "## SplitConfig Configuration",
+        "",
+        "| Field Name | Type | Required | Default | Env | Details     |",
+        "|------------|------|----------|---------|-----|-------------|",
+        "| port       | u16  | No       | 8080    | -   | Server port |",
+        "",
+        "",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,43 @@
+mod common;
+
+use common::Fixture;
+
+/// Build the stdout fixture with `CLAP_AUTODOC_STDOUT=1` and return what the build printed
+fn build_with_stdout(fixture: &Fixture, check: bool) -> String {
+    let output = fixture.check(&[
+        ("CLAP_AUTODOC_STDOUT", "1"),
+        ("CLAP_AUTODOC_CHECK", if check { "1" } else { "0" }),
+    ]);
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn test_stdout() {
+    let fixture = Fixture::lock("stdout");
+    let stdout = build_with_stdout(&fixture, false);
+
+    let expected = [
+        "===== clap_autodoc: ../output/stdout_output.md =====",
+        "| Field Name    | Type   | Required | Default | Env           | Details       | Group        |",
+        "|---------------|--------|----------|---------|---------------|---------------|--------------|",
+        "| database-host | String | Yes      | -       | DATABASE_HOST | Database host | StdoutConfig |",
+        "===== end clap_autodoc: ../output/stdout_output.md =====",
+    ]
+    .join("\n");
+
+    assert_eq!(stdout.trim(), expected.trim());
+}
+
+#[test]
+fn test_stdout_in_check_mode() {
+    // The fixture's docs are up to date after the first build, check mode prints nothing
+    let fixture = Fixture::lock("stdout");
+    build_with_stdout(&fixture, false);
+    assert_eq!(build_with_stdout(&fixture, true), "");
+}
@@ -0,0 +1,36 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+/// Settings of the metrics exporter.
+///
+/// Every option can also be set through its environment variable.
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/struct_intro_output.md")]
+pub struct ExporterConfig {
+    /// Address to listen on
+    #[clap(long, env = "EXPORTER_LISTEN", default_value = "0.0.0.0:9100")]
+    pub listen: String,
+}
+
+#[test]
+fn test_struct_doc_comment_intro() {
+    let content = std::fs::read_to_string("tests/output/struct_intro_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "Settings of the metrics exporter.",
+        "",
+        "Every option can also be set through its environment variable.",
+        "",
+        "| Field Name | Type   | Required | Default      | Env             | Details                                                                           | Group          |",
+        "|------------|--------|----------|--------------|-----------------|-----------------------------------------------------------------------------------|----------------|",
+        "| listen     | String | No       | 0.0.0.0:9100 | EXPORTER_LISTEN | Address to listen on (default if neither `--listen` nor `EXPORTER_LISTEN` is set) | ExporterConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
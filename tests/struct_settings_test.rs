@@ -0,0 +1,36 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[command(
+    rename_all = "kebab-case",
+    help_expected = true,
+    mut_arg("database_host", |arg| arg.short('d'))
+)]
+#[generate(target = "tests/output/struct_settings_output.md")]
+pub struct SettingsConfig {
+    /// Database host
+    #[clap(long)]
+    pub database_host: String,
+}
+
+#[test]
+fn test_unfamiliar_struct_settings_are_tolerated() {
+    let config = SettingsConfig::parse_from(["test", "-d", "localhost"]);
+    assert_eq!(config.database_host, "localhost");
+
+    let content = std::fs::read_to_string("tests/output/struct_settings_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name    | Type   | Required | Default | Env | Details       | Group          |",
+        "|---------------|--------|----------|---------|-----|---------------|----------------|",
+        "| database-host | String | Yes      | -       | -   | Database host | SettingsConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,36 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/summary_format_output.md", format = "summary")]
+pub struct SummaryConfig {
+    /// Database host
+    #[clap(long, env = "POSTGRES_HOST")]
+    pub postgres_host: String,
+
+    /// Database port
+    #[clap(long, default_value_t = 5432)]
+    pub postgres_port: u16,
+
+    #[clap(long)]
+    pub replicas: Vec<String>,
+}
+
+#[test]
+fn test_summary_format() {
+    let content = std::fs::read_to_string("tests/output/summary_format_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "- postgres-host — Database host [String, required, env POSTGRES_HOST]",
+        "- postgres-port — Database port [u16, default 5432]",
+        "- replicas [String (multiple), optional]",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
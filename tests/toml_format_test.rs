@@ -0,0 +1,92 @@
+use clap::{Args, Parser};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Args)]
+#[register]
+pub struct TomlDatabaseConfig {
+    /// Database host
+    #[clap(long, default_value = "localhost")]
+    pub database_host: String,
+
+    /// Database port
+    #[clap(long, default_value_t = 5432)]
+    pub database_port: u16,
+
+    /// Database password
+    #[clap(long)]
+    pub database_password: String,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[generate(target = "tests/output/toml_format_output.md", format = "toml")]
+pub struct TomlConfig {
+    /// Database configuration
+    #[clap(flatten)]
+    pub database: TomlDatabaseConfig,
+
+    /// Name of the service
+    #[clap(long)]
+    pub service_name: String,
+
+    /// Request timeout in seconds
+    #[clap(long, default_value_t = 2.5)]
+    pub timeout: f64,
+
+    #[clap(long)]
+    pub verbose: bool,
+
+    /// File mode of created files
+    #[clap(long, default_value = "0755")]
+    pub mode: String,
+
+    /// Protocol version
+    #[clap(long, default_value = "1.")]
+    pub version: String,
+
+    /// Scale factor
+    #[clap(long, default_value_t = 1.)]
+    pub scale: f64,
+}
+
+#[test]
+fn test_toml_format() {
+    let content = std::fs::read_to_string("tests/output/toml_format_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "```toml",
+        "# Name of the service",
+        "# service-name = <String>",
+        "",
+        "# Request timeout in seconds",
+        "timeout = 2.5",
+        "",
+        "verbose = false",
+        "",
+        "# File mode of created files",
+        "mode = \"0755\"",
+        "",
+        "# Protocol version",
+        "version = \"1.\"",
+        "",
+        "# Scale factor",
+        "scale = 1.0",
+        "",
+        "[toml_database_config]",
+        "# Database host",
+        "database-host = \"localhost\"",
+        "",
+        "# Database port",
+        "database-port = 5432",
+        "",
+        "# Database password",
+        "# database-password = <String>",
+        "```",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,36 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/trailing_args_output.md")]
+pub struct WrapperConfig {
+    /// Program to run
+    pub program: String,
+
+    /// Arguments passed to the program
+    #[clap(trailing_var_arg = true, allow_hyphen_values = true)]
+    pub args: Vec<String>,
+}
+
+#[test]
+fn test_trailing_var_arg_with_hyphen_values() {
+    let config = WrapperConfig::parse_from(["test", "cargo", "build", "--release"]);
+    assert_eq!(config.args, ["build", "--release"]);
+
+    let content = std::fs::read_to_string("tests/output/trailing_args_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type              | Required | Default | Env | Details                                                                                             | Group         |",
+        "|------------|-------------------|----------|---------|-----|-----------------------------------------------------------------------------------------------------|---------------|",
+        "| program    | String            | Yes      | -       | -   | Program to run                                                                                      | WrapperConfig |",
+        "| args       | String (multiple) | No       | -       | -   | Arguments passed to the program (captures all trailing arguments, including ones starting with `-`) | WrapperConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,41 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/type_style_output.md", type_style = "clap")]
+pub struct TypeStyleConfig {
+    /// Server host
+    #[clap(env = "HOST", long)]
+    pub host: String,
+
+    /// Server port
+    #[clap(env = "PORT", long)]
+    pub port: Option<u16>,
+
+    /// Request tags
+    #[clap(env = "TAGS", long)]
+    pub tags: Vec<String>,
+}
+
+#[test]
+fn test_clap_type_style() {
+    assert!(std::path::Path::new("tests/output/type_style_output.md").exists());
+
+    let content = std::fs::read_to_string("tests/output/type_style_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type        | Required | Default | Env  | Details      | Group           |",
+        "|------------|-------------|----------|---------|------|--------------|-----------------|",
+        "| host       | `<HOST>`    | Yes      | -       | HOST | Server host  | TypeStyleConfig |",
+        "| port       | `[<PORT>]`  | No       | -       | PORT | Server port  | TypeStyleConfig |",
+        "| tags       | `<TAGS>...` | No       | -       | TAGS | Request tags | TypeStyleConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
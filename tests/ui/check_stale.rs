@@ -0,0 +1,27 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "check_stale_output.md")]
+pub struct CommittedConfig {
+    /// Database host
+    #[clap(long)]
+    pub database_host: String,
+}
+
+// The struct gained a field without the documentation being regenerated
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "check_stale_output.md", check = true)]
+pub struct EditedConfig {
+    /// Database host
+    #[clap(long)]
+    pub database_host: String,
+
+    /// Database port
+    #[clap(long)]
+    pub database_port: u16,
+}
+
+fn main() {}
@@ -0,0 +1,27 @@
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug)]
+#[register]
+pub struct BConfig {
+    /// Back reference
+    #[clap_autodoc(expand)]
+    pub a: Vec<AConfig>,
+}
+
+#[derive(Clone, Debug)]
+#[register]
+pub struct AConfig {
+    /// Forward reference
+    #[clap_autodoc(expand)]
+    pub b: BConfig,
+}
+
+#[derive(Clone, Debug)]
+#[generate(target = "circular_flatten_output.md")]
+pub struct RootConfig {
+    /// Entry point
+    #[clap_autodoc(expand)]
+    pub a: AConfig,
+}
+
+fn main() {}
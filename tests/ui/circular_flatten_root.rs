@@ -0,0 +1,19 @@
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug)]
+#[register]
+pub struct BConfig {
+    /// Back reference to the generated struct
+    #[clap_autodoc(expand)]
+    pub a: AConfig,
+}
+
+#[derive(Clone, Debug)]
+#[generate(target = "circular_flatten_root_output.md")]
+pub struct AConfig {
+    /// Forward reference
+    #[clap_autodoc(expand)]
+    pub b: Vec<BConfig>,
+}
+
+fn main() {}
@@ -0,0 +1,22 @@
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug)]
+#[register]
+pub struct Node {
+    /// Node name
+    pub name: String,
+
+    /// Child nodes
+    #[clap_autodoc(expand)]
+    pub children: Vec<Node>,
+}
+
+#[derive(Clone, Debug)]
+#[generate(target = "cyclic_expand_output.md")]
+pub struct Tree {
+    /// Root node
+    #[clap_autodoc(expand)]
+    pub root: Node,
+}
+
+fn main() {}
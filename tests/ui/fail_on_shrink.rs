@@ -0,0 +1,32 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "shrink_guard_output.md")]
+pub struct FullConfig {
+    /// Database host the service connects to
+    #[clap(long, env = "DATABASE_HOST")]
+    pub database_host: String,
+
+    /// Database port the service connects to
+    #[clap(long, env = "DATABASE_PORT", default_value_t = 5432)]
+    pub database_port: u16,
+
+    /// Maximum number of pooled database connections
+    #[clap(long, env = "DATABASE_POOL_SIZE", default_value_t = 16)]
+    pub database_pool_size: u32,
+
+    /// Timeout for establishing a database connection, in seconds
+    #[clap(long, env = "DATABASE_CONNECT_TIMEOUT", default_value_t = 30)]
+    pub database_connect_timeout: u64,
+}
+
+// Replaces the table above with a fraction of its size
+#[derive(Clone, Debug, Parser)]
+#[generate(target = "shrink_guard_output.md", fail_on_shrink = true)]
+pub struct TruncatedConfig {
+    pub a: String,
+}
+
+fn main() {}
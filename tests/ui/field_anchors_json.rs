@@ -0,0 +1,16 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[generate(
+    target = "field_anchors_json_output.md",
+    format = "json",
+    field_anchors = true
+)]
+pub struct FieldAnchorsJsonConfig {
+    /// Server host
+    #[clap(long)]
+    pub host: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[generate(
+    target = "tests/output/field_filter_regex_output.md",
+    field_filter = "^postgres_"
+)]
+pub struct FilterConfig {
+    /// Database host
+    #[clap(long)]
+    pub postgres_host: String,
+}
+
+fn main() {}
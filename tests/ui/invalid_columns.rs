@@ -0,0 +1,12 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[generate(target = "invalid_columns_output.md", columns = "name,description")]
+pub struct InvalidColumnsConfig {
+    /// Server host
+    #[clap(long)]
+    pub host: String,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[generate(target = "invalid_merge_output.md", merge = ["DatabaseConfig"])]
+pub struct InvalidMergeConfig {
+    /// Server host
+    #[clap(long)]
+    pub host: String,
+}
+
+fn main() {}
@@ -0,0 +1,15 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[generate(
+    target = "invalid_min_col_width_output.md",
+    min_col_width = "name:18,size:8"
+)]
+pub struct InvalidWidthConfig {
+    /// Server host
+    #[clap(long)]
+    pub host: String,
+}
+
+fn main() {}
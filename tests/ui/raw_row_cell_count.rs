@@ -0,0 +1,14 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/raw_row_cell_count_output.md")]
+pub struct RawRowConfig {
+    /// Worker threads
+    #[clap(env = "WORKERS", long, default_value_t = 0)]
+    #[clap_autodoc(raw_row = "| workers | usize | No |")]
+    pub workers: usize,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use clap_autodoc::render;
+
+pub struct NotRegistered {
+    pub host: String,
+}
+
+fn main() {
+    let _docs = render!(NotRegistered);
+}
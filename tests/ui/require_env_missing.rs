@@ -0,0 +1,24 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/require_env_missing_output.md",
+    require_env = true
+)]
+pub struct TwelveFactorConfig {
+    /// Database URL
+    #[clap(long, env = "DATABASE_URL")]
+    pub database_url: String,
+
+    /// Listen port
+    #[clap(long, default_value_t = 8080)]
+    pub port: u16,
+
+    /// Print more output
+    #[clap(long)]
+    pub verbose: bool,
+}
+
+fn main() {}
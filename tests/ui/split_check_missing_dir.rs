@@ -0,0 +1,18 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+// Check mode must not create the missing directory, the docs are reported as out of date
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    split_by_group = true,
+    split_target_template = "missing_split_dir/{group}.md",
+    check = true
+)]
+pub struct SplitCheckConfig {
+    /// Server host
+    #[clap(long)]
+    pub host: String,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[generate(
+    split_by_group = true,
+    split_target_template = "docs/{group}.md",
+    emit_checksum = "docs/config.md.fnv"
+)]
+pub struct SplitConfig {
+    /// Server port
+    #[clap(long)]
+    pub port: u16,
+}
+
+fn main() {}
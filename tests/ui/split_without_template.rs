@@ -0,0 +1,12 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[generate(split_by_group = true, split_target_template = "docs/config.md")]
+pub struct SplitConfig {
+    /// Server port
+    #[clap(long)]
+    pub port: u16,
+}
+
+fn main() {}
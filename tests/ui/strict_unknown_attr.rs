@@ -0,0 +1,13 @@
+use clap::{Parser, ValueHint};
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/strict_output.md", strict = true)]
+pub struct StrictConfig {
+    /// Database host
+    #[clap(env = "DB_HOST", long, value_hint = ValueHint::Hostname)]
+    pub db_host: String,
+}
+
+fn main() {}
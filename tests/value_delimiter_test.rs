@@ -0,0 +1,59 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+// clap 4 removed `require_value_delimiter`; this shim lets clap 3 style code compile
+trait LegacyArgExt {
+    fn require_value_delimiter(self, yes: bool) -> Self;
+}
+
+impl LegacyArgExt for clap::Arg {
+    fn require_value_delimiter(self, _yes: bool) -> Self {
+        self
+    }
+}
+
+const TAG_DELIMITER: char = ';';
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/value_delimiter_output.md")]
+pub struct DelimiterConfig {
+    /// Database hosts
+    #[clap(
+        env = "DB_HOSTS",
+        long,
+        value_delimiter = ',',
+        require_value_delimiter = true
+    )]
+    pub db_hosts: Vec<String>,
+
+    /// Search paths
+    #[clap(env = "SEARCH_PATHS", long, value_delimiter = ':')]
+    pub search_paths: Vec<String>,
+
+    /// Tags
+    #[clap(long, value_delimiter = TAG_DELIMITER)]
+    pub tags: Vec<String>,
+}
+
+#[test]
+fn test_value_delimiter_notes() {
+    assert!(std::path::Path::new("tests/output/value_delimiter_output.md").exists());
+
+    let content = std::fs::read_to_string("tests/output/value_delimiter_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name   | Type              | Required | Default | Env          | Details                                                                                 | Group           |",
+        "|--------------|-------------------|----------|---------|--------------|-----------------------------------------------------------------------------------------|-----------------|",
+        "| db-hosts     | String (multiple) | No       | -       | DB_HOSTS     | Database hosts (requires comma-separated values, on the command line and in `DB_HOSTS`) | DelimiterConfig |",
+        "| search-paths | String (multiple) | No       | -       | SEARCH_PATHS | Search paths (colon-separated values, on the command line and in `SEARCH_PATHS`)        | DelimiterConfig |",
+        "| tags         | String (multiple) | No       | -       | -            | Tags                                                                                    | DelimiterConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,73 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+const TIMEOUT_VALUE_NAME: &str = "SECONDS";
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/value_name_output.md")]
+pub struct EndpointConfig {
+    /// Endpoint to send metrics to
+    #[clap(long, value_name = "URL")]
+    pub endpoint: String,
+
+    /// Extra headers
+    #[clap(long, value_name = "NAME=VALUE")]
+    pub header: Vec<String>,
+
+    /// Request timeout in seconds
+    #[clap(long, default_value_t = 30)]
+    pub timeout: u64,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/value_name_clap_output.md", type_style = "clap")]
+pub struct EndpointClapConfig {
+    /// Endpoint to send metrics to
+    #[clap(long, value_name = "URL")]
+    pub endpoint: String,
+
+    /// Request timeout in seconds
+    #[clap(long, value_name = TIMEOUT_VALUE_NAME, default_value_t = 30)]
+    pub timeout: u64,
+}
+
+#[test]
+fn test_value_name() {
+    let content = std::fs::read_to_string("tests/output/value_name_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type                           | Required | Default | Env | Details                     | Group          |",
+        "|------------|--------------------------------|----------|---------|-----|-----------------------------|----------------|",
+        "| endpoint   | String (URL)                   | Yes      | -       | -   | Endpoint to send metrics to | EndpointConfig |",
+        "| header     | String (NAME=VALUE) (multiple) | No       | -       | -   | Extra headers               | EndpointConfig |",
+        "| timeout    | u64                            | No       | 30      | -   | Request timeout in seconds  | EndpointConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_value_name_clap_type_style() {
+    let content = std::fs::read_to_string("tests/output/value_name_clap_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type          | Required | Default | Env | Details                     | Group              |",
+        "|------------|---------------|----------|---------|-----|-----------------------------|--------------------|",
+        "| endpoint   | `<URL>`       | Yes      | -       | -   | Endpoint to send metrics to | EndpointClapConfig |",
+        "| timeout    | `[<TIMEOUT>]` | No       | 30      | -   | Request timeout in seconds  | EndpointClapConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
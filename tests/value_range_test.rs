@@ -0,0 +1,55 @@
+use clap::builder::{RangedI64ValueParser, RangedU64ValueParser};
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/value_range_output.md")]
+pub struct RangeConfig {
+    /// Worker threads
+    #[clap(long, default_value_t = 4, value_parser = RangedU64ValueParser::<u64>::new().range(1..=64))]
+    pub workers: u64,
+
+    /// Process priority
+    #[clap(long, default_value_t = 0, value_parser = RangedI64ValueParser::<i64>::new().range(-20..20))]
+    pub priority: i64,
+
+    /// Port to listen on
+    #[clap(long, default_value_t = 8080, value_parser = clap::value_parser!(u16).range(1024..))]
+    pub port: u16,
+
+    /// Log verbosity
+    #[clap(long, default_value_t = 2, value_parser = RangedI64ValueParser::<i32>::from(1..=10))]
+    pub verbosity: i32,
+
+    /// Retries before giving up
+    #[clap(long, default_value_t = 3, value_parser = RangedU64ValueParser::<u8>::from(..5))]
+    pub retries: u8,
+}
+
+#[test]
+fn test_ranged_value_parsers() {
+    let error = RangeConfig::try_parse_from(["test", "--workers", "65"]).unwrap_err();
+    assert_eq!(error.kind(), clap::error::ErrorKind::ValueValidation);
+    let error = RangeConfig::try_parse_from(["test", "--verbosity", "11"]).unwrap_err();
+    assert_eq!(error.kind(), clap::error::ErrorKind::ValueValidation);
+
+    let content = std::fs::read_to_string("tests/output/value_range_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type | Required | Default | Env | Details                               | Group       |",
+        "|------------|------|----------|---------|-----|---------------------------------------|-------------|",
+        "| workers    | u64  | No       | 4       | -   | Worker threads (between 1 and 64)     | RangeConfig |",
+        "| priority   | i64  | No       | 0       | -   | Process priority (between -20 and 19) | RangeConfig |",
+        "| port       | u16  | No       | 8080    | -   | Port to listen on (at least 1024)     | RangeConfig |",
+        "| verbosity  | i32  | No       | 2       | -   | Log verbosity (between 1 and 10)      | RangeConfig |",
+        "| retries    | u8   | No       | 3       | -   | Retries before giving up (at most 4)  | RangeConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,86 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/vec_output.md", legend = true)]
+pub struct VecConfig {
+    /// Upstream servers
+    #[clap(long)]
+    pub upstream: Vec<String>,
+
+    /// Allowed ports
+    #[clap(long)]
+    pub allowed_ports: Option<Vec<u16>>,
+
+    /// Service name
+    #[clap(long)]
+    pub name: String,
+}
+
+// clap's derive only recognizes a bare `Vec`, so this struct is not parsed by clap
+#[derive(Clone, Debug)]
+#[generate(target = "tests/output/vec_qualified_output.md")]
+pub struct QualifiedVecConfig {
+    /// Search paths
+    pub search_paths: std::vec::Vec<std::path::PathBuf>,
+}
+
+#[test]
+fn test_vec_fields_are_multiple() {
+    let config = VecConfig::parse_from([
+        "test",
+        "--name",
+        "api",
+        "--upstream",
+        "a",
+        "--upstream",
+        "b",
+    ]);
+    assert_eq!(config.upstream, ["a", "b"]);
+
+    let content = std::fs::read_to_string("tests/output/vec_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name    | Type              | Required | Default | Env | Details          | Group     |",
+        "|---------------|-------------------|----------|---------|-----|------------------|-----------|",
+        "| upstream      | String (multiple) | No       | -       | -   | Upstream servers | VecConfig |",
+        "| allowed-ports | u16 (multiple)    | No       | -       | -   | Allowed ports    | VecConfig |",
+        "| name          | String            | Yes      | -       | -   | Service name     | VecConfig |",
+        "",
+        "**Legend**",
+        "",
+        "- **Field Name**: name of the command-line argument",
+        "- **Type**: type of the value",
+        "- **Required**: `Yes` if the value has to be provided, `No` if it is optional or has a default",
+        "- **Default**: value used when the option is not set",
+        "- **Env**: environment variable the value is read from",
+        "- **Details**: description and notes on how the value is parsed",
+        "- **Group**: struct the field is declared in",
+        "- `-` in Env: the field has no environment variable",
+        "- `-` in Default: the field has no default value",
+        "- `(multiple)` in Type: the value may be given multiple times",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+
+    let content = std::fs::read_to_string("tests/output/vec_qualified_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name   | Type                          | Required | Default | Env | Details      | Group              |",
+        "|--------------|-------------------------------|----------|---------|-----|--------------|--------------------|",
+        "| search-paths | std::path::PathBuf (multiple) | No       | -       | -   | Search paths | QualifiedVecConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
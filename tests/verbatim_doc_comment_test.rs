@@ -0,0 +1,36 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/verbatim_doc_comment_output.md")]
+pub struct LogConfig {
+    /// Log filter directives, for example:
+    ///   info
+    ///   my_crate=debug
+    #[clap(long, verbatim_doc_comment)]
+    pub log_filter: String,
+
+    /// Log format
+    #[clap(long)]
+    pub log_format: String,
+}
+
+#[test]
+fn test_verbatim_doc_comment_keeps_line_breaks() {
+    let content = std::fs::read_to_string("tests/output/verbatim_doc_comment_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details                                                                               | Group     |",
+        "|------------|--------|----------|---------|-----|---------------------------------------------------------------------------------------|-----------|",
+        "| log-filter | String | Yes      | -       | -   | Log filter directives, for example:<br>&nbsp;&nbsp;info<br>&nbsp;&nbsp;my_crate=debug | LogConfig |",
+        "| log-format | String | Yes      | -       | -   | Log format                                                                            | LogConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
@@ -0,0 +1,17 @@
+use clap::Parser;
+use clap_autodoc::generate;
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "warnings_docs.md", warnings = true)]
+pub struct WarningsConfig {
+    /// Database host
+    #[clap(long, env = "DATABASE_HOST")]
+    pub database_host: String,
+
+    #[clap(long, env = "DATABASE_HOST")]
+    pub database_port: u16,
+
+    #[clap(long)]
+    pub database_name: String,
+}
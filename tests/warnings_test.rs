@@ -0,0 +1,27 @@
+mod common;
+
+use common::Fixture;
+
+#[test]
+fn test_warnings_are_collated_per_struct() {
+    let output = Fixture::lock("warnings").check(&[]);
+    assert!(output.status.success());
+
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    let warning = stderr
+        .lines()
+        .skip_while(|line| !line.starts_with("warning: clap_autodoc"))
+        .take_while(|line| line.starts_with("warning: clap_autodoc") || line.starts_with("  - "))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let expected = [
+        "warning: clap_autodoc found 3 issues in WarningsConfig",
+        "  - database-port: field has no description (undocumented)",
+        "  - database-port: environment variable DATABASE_HOST is also used by database-host (duplicate_env)",
+        "  - database-name: field has no description (undocumented)",
+    ]
+    .join("\n");
+
+    assert_eq!(warning, expected);
+}
@@ -0,0 +1,83 @@
+use clap::Parser;
+use clap_autodoc::{generate, written_paths};
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/write_if_absent_output.md")]
+pub struct ScaffoldConfig {
+    /// Server host
+    #[clap(long)]
+    pub host: String,
+}
+
+// Generates into the region written above, so it has to leave it untouched
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/write_if_absent_output.md",
+    write_if_absent_only = true
+)]
+pub struct OverwritingConfig {
+    /// Server port
+    #[clap(long, default_value_t = 8080)]
+    pub port: u16,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/write_if_absent_output.env", format = "dotenv")]
+pub struct ScaffoldDotenvConfig {
+    /// Server host
+    #[clap(long, env = "HOST")]
+    pub host: String,
+}
+
+// A dotenv file has no markers, so the whole file is kept once it has content
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(
+    target = "tests/output/write_if_absent_output.env",
+    format = "dotenv",
+    write_if_absent_only = true
+)]
+pub struct OverwritingDotenvConfig {
+    /// Server port
+    #[clap(long, env = "PORT", default_value_t = 8080)]
+    pub port: u16,
+}
+
+#[test]
+fn test_write_if_absent_only_keeps_existing_region() {
+    let content = std::fs::read_to_string("tests/output/write_if_absent_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "| Field Name | Type   | Required | Default | Env | Details     | Group          |",
+        "|------------|--------|----------|---------|-----|-------------|----------------|",
+        "| host       | String | Yes      | -       | -   | Server host | ScaffoldConfig |",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}
+
+#[test]
+fn test_write_if_absent_only_keeps_existing_dotenv_file() {
+    let content = std::fs::read_to_string("tests/output/write_if_absent_output.env").unwrap();
+    assert_eq!(content, "# Server host\nHOST=\n");
+}
+
+#[test]
+fn test_kept_region_is_recorded() {
+    let paths: &[&str] = written_paths!(OverwritingConfig);
+    assert_eq!(
+        paths,
+        [concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/output/write_if_absent_output.md"
+        )]
+    );
+}
@@ -0,0 +1,38 @@
+use clap::Parser;
+use clap_autodoc::{generate, register, written_paths};
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/written_paths_output.md")]
+pub struct WrittenConfig {
+    /// Database configuration
+    #[clap(flatten)]
+    pub database: DatabaseConfig,
+}
+
+// Registered after the main struct, so the documentation is written on registration
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[register]
+pub struct DatabaseConfig {
+    /// Database host
+    #[clap(env = "DB_HOST", long)]
+    pub db_host: String,
+}
+
+#[test]
+fn test_written_paths() {
+    let paths: &[&str] = written_paths!(WrittenConfig);
+    assert_eq!(
+        paths,
+        [concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/output/written_paths_output.md"
+        )]
+    );
+
+    let content = std::fs::read_to_string(paths[0]).unwrap();
+    assert!(content.contains(
+        "| db-host    | String | Yes      | -       | DB_HOST | Database host | DatabaseConfig |"
+    ));
+}
@@ -0,0 +1,65 @@
+use clap::{Args, Parser};
+use clap_autodoc::{generate, register};
+
+#[derive(Clone, Debug, Args)]
+#[register]
+pub struct YamlDatabaseConfig {
+    /// Database host
+    #[clap(long, default_value = "localhost")]
+    pub database_host: String,
+
+    /// Database port
+    #[clap(long, default_value_t = 5432)]
+    pub database_port: u16,
+}
+
+#[derive(Clone, Debug, Parser)]
+#[clap(rename_all = "kebab-case")]
+#[generate(target = "tests/output/yaml_format_output.md", format = "yaml")]
+pub struct YamlConfig {
+    /// Database configuration
+    #[clap(flatten)]
+    pub database: YamlDatabaseConfig,
+
+    /// Name of the service
+    #[clap(long)]
+    pub service_name: String,
+
+    #[clap(long)]
+    pub verbose: bool,
+
+    #[clap(skip)]
+    pub internal: u8,
+}
+
+#[test]
+fn test_yaml_format() {
+    let content = std::fs::read_to_string("tests/output/yaml_format_output.md").unwrap();
+
+    let expected = [
+        "[//]: # (CONFIG_DOCS_START)",
+        "",
+        "```yaml",
+        "# YamlDatabaseConfig",
+        "",
+        "# Database host (default: localhost)",
+        "database-host: \"localhost\"",
+        "",
+        "# Database port (default: 5432)",
+        "database-port: 5432",
+        "",
+        "# YamlConfig",
+        "",
+        "# Name of the service",
+        "service-name: ~",
+        "",
+        "# (default: false)",
+        "verbose: false",
+        "```",
+        "",
+        "[//]: # (CONFIG_DOCS_END)",
+    ]
+    .join("\n");
+
+    assert_eq!(content.trim(), expected.trim());
+}